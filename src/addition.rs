@@ -1,5 +1,8 @@
 use super::Nimber;
+use crate::multiplication::Smallest;
+use crate::repr::NimberRepr;
 
+use std::iter::Sum;
 use std::ops::{
     Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Neg, Not, Shl,
     ShlAssign, Shr, ShrAssign, Sub, SubAssign,
@@ -67,3 +70,23 @@ where
         Nimber::from(!&self.x)
     }
 }
+
+impl<T: NimberRepr> Sum for Nimber<T> {
+    #[inline]
+    fn sum<I: Iterator<Item = Nimber<T>>>(iter: I) -> Self {
+        iter.fold(Nimber::from(T::from(0 as Smallest)), |mut acc, a| {
+            acc += a;
+            acc
+        })
+    }
+}
+
+impl<'a, T: NimberRepr> Sum<&'a Nimber<T>> for Nimber<T> {
+    #[inline]
+    fn sum<I: Iterator<Item = &'a Nimber<T>>>(iter: I) -> Self {
+        iter.fold(Nimber::from(T::from(0 as Smallest)), |mut acc, a| {
+            acc += a;
+            acc
+        })
+    }
+}
@@ -0,0 +1,229 @@
+// Hardware-accelerated multiplication of Nim64 and Nim128
+// A nimber a outside the subfield below 2^32 has degree 64 over GF(2),
+// so 1, a, ..., a^63 is a basis of Nim64 and a nimber is a polynomial of degree < 64 in a.
+// Two such polynomials are multiplied by the carry-less multiplication of the CPU,
+// and the product, a polynomial of degree < 127 in a, is mapped back to the nimber basis
+// by a fixed linear map, without any reduction modulo the minimal polynomial of a.
+// Both changes of basis are applied by byte tables built at compile time.
+// Nim128 takes one Karatsuba step of the nimber recursion on top of Nim64
+
+use crate::{Nim128, Nim32, Nim64};
+
+// the fermat 2-power of level 6, X^2 = X + FERMAT at level 7
+const FERMAT: Nim64 = Nim64::new(1 << 63);
+
+// the same at level 5
+const FERMAT32: Nim32 = Nim32::new(1 << 31);
+
+// a GF(2)-linear map from N bytes to 64 bits, as the images of every value of each byte
+struct Linear<const N: usize> {
+    bytes: [[u64; 256]; N],
+}
+
+impl<const N: usize> Linear<N> {
+    // the map sending bit i to columns[i]
+    const fn new<const M: usize>(columns: &[u64; M]) -> Self {
+        assert!(M == 8 * N);
+
+        let mut linear = Linear {
+            bytes: [[0; 256]; N],
+        };
+
+        let mut i = 0;
+
+        while i < N {
+            let mut b = 1;
+
+            while b < 256 {
+                // b without its lowest bit has already been mapped
+                linear.bytes[i][b] =
+                    linear.bytes[i][b & (b - 1)] ^ columns[8 * i + b.trailing_zeros() as usize];
+                b += 1;
+            }
+
+            i += 1;
+        }
+
+        linear
+    }
+
+    #[inline]
+    fn apply(&self, x: u128) -> u64 {
+        let mut y = 0;
+
+        for i in 0..N {
+            y ^= self.bytes[i][(x >> (8 * i)) as u8 as usize];
+        }
+
+        y
+    }
+}
+
+// the powers of the element a = 1 << 32, the X of level 6, each multiplied by factor;
+// (h X + l) X = (h + l) X + h FERMAT32 takes a single product at level 5
+const fn powers(factor: Nim64) -> [u64; 128] {
+    let mut powers = [0; 128];
+    let mut x = factor.get();
+    let mut i = 0;
+
+    while i < 128 {
+        powers[i] = x;
+
+        let (h, l) = ((x >> 32) as u32, x as u32);
+        let h_fermat = Nim32::new(h).const_mul(FERMAT32).get();
+
+        x = ((h ^ l) as u64) << 32 | h_fermat as u64;
+        i += 1;
+    }
+
+    powers
+}
+
+const POWERS: [u64; 128] = powers(Nim64::ONE);
+
+// the coordinates of the nimber basis in the polynomial basis,
+// by Gaussian elimination of the pairs (a^i, 1 << i) until the nimber of pair k is 1 << k
+const fn coordinates() -> [u64; 64] {
+    let mut nimbers = [0; 64];
+    let mut coordinates = [0; 64];
+    let mut i = 0;
+
+    while i < 64 {
+        nimbers[i] = POWERS[i];
+        coordinates[i] = 1 << i;
+        i += 1;
+    }
+
+    let mut k = 0;
+
+    while k < 64 {
+        let mut pivot = k;
+
+        while nimbers[pivot] >> k & 1 == 0 {
+            pivot += 1;
+        }
+
+        let (x, c) = (nimbers[pivot], coordinates[pivot]);
+        nimbers[pivot] = nimbers[k];
+        coordinates[pivot] = coordinates[k];
+        nimbers[k] = x;
+        coordinates[k] = c;
+
+        let mut i = 0;
+
+        while i < 64 {
+            if i != k && nimbers[i] >> k & 1 == 1 {
+                nimbers[i] ^= x;
+                coordinates[i] ^= c;
+            }
+
+            i += 1;
+        }
+
+        k += 1;
+    }
+
+    coordinates
+}
+
+// from the nimber basis to the polynomial one
+static TO_POLY: Linear<8> = Linear::new(&coordinates());
+
+// from the products in the polynomial basis to the nimber one
+static FROM_POLY: Linear<16> = Linear::new(&POWERS);
+
+// the same, followed by the multiplication by FERMAT
+static FROM_POLY_FERMAT: Linear<16> = Linear::new(&powers(FERMAT));
+
+#[cfg(target_arch = "x86_64")]
+mod arch {
+    use std::arch::x86_64::{__m128i, _mm_clmulepi64_si128, _mm_cvtsi64_si128};
+    use std::mem::transmute;
+
+    #[inline]
+    pub(super) fn detected() -> bool {
+        is_x86_feature_detected!("pclmulqdq")
+    }
+
+    #[inline]
+    #[target_feature(enable = "pclmulqdq")]
+    pub(super) unsafe fn clmul(a: u64, b: u64) -> u128 {
+        let c = _mm_clmulepi64_si128(_mm_cvtsi64_si128(a as i64), _mm_cvtsi64_si128(b as i64), 0);
+
+        transmute::<__m128i, u128>(c)
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod arch {
+    use std::arch::aarch64::vmull_p64;
+    use std::arch::is_aarch64_feature_detected;
+
+    // PMULL comes with the AES extension
+    #[inline]
+    pub(super) fn detected() -> bool {
+        is_aarch64_feature_detected!("aes")
+    }
+
+    #[inline]
+    #[target_feature(enable = "aes")]
+    pub(super) unsafe fn clmul(a: u64, b: u64) -> u128 {
+        vmull_p64(a, b)
+    }
+}
+
+// whether the CPU has a carry-less multiplication; the detection is cached by std
+#[inline]
+pub(crate) fn detected() -> bool {
+    arch::detected()
+}
+
+#[inline]
+fn to_poly(a: u64) -> u64 {
+    TO_POLY.apply(a as u128)
+}
+
+#[cfg_attr(target_arch = "x86_64", target_feature(enable = "pclmulqdq"))]
+#[cfg_attr(target_arch = "aarch64", target_feature(enable = "aes"))]
+pub(crate) unsafe fn mul_u64(a: u64, b: u64) -> u64 {
+    FROM_POLY.apply(arch::clmul(to_poly(a), to_poly(b)))
+}
+
+// one step of the recursion, with the three half products in the polynomial basis
+#[cfg_attr(target_arch = "x86_64", target_feature(enable = "pclmulqdq"))]
+#[cfg_attr(target_arch = "aarch64", target_feature(enable = "aes"))]
+pub(crate) unsafe fn mul_u128(a: u128, b: u128) -> u128 {
+    let (ah, al) = (to_poly((a >> 64) as u64), to_poly(a as u64));
+    let (bh, bl) = (to_poly((b >> 64) as u64), to_poly(b as u64));
+
+    let low = arch::clmul(al, bl);
+    let high = arch::clmul(ah, bh);
+    let mid = arch::clmul(ah ^ al, bh ^ bl);
+
+    let (high, low) = (
+        FROM_POLY.apply(mid ^ low),
+        FROM_POLY.apply(low) ^ FROM_POLY_FERMAT.apply(high),
+    );
+
+    ((high as u128) << 64) | low as u128
+}
+
+#[inline]
+pub(crate) fn nimber_mul_u64(a: &Nim64, b: &Nim64) -> Nim64 {
+    if detected() {
+        // SAFETY: the CPU has a carry-less multiplication
+        return Nim64::new(unsafe { mul_u64(a.x, b.x) });
+    }
+
+    a.const_mul(*b)
+}
+
+#[inline]
+pub(crate) fn nimber_mul_u128(a: &Nim128, b: &Nim128) -> Nim128 {
+    if detected() {
+        // SAFETY: the CPU has a carry-less multiplication
+        return Nim128::new(unsafe { mul_u128(a.x, b.x) });
+    }
+
+    a.const_mul(*b)
+}
@@ -0,0 +1,81 @@
+// Conjugates and minimal polynomials of nimbers
+// A nimber at level k has degree 2^k over GF(2), as all subfields are nimber subfields
+// The minimal polynomial is built down the tower: the product of the conjugates over level j
+// is the product of the polynomial over level j + 1 and its image under X -> X + 1,
+// so its coefficients shrink to the lower level at every step
+
+use super::Nimber;
+use crate::multiplication::{
+    high_part, level, nimber_mul_nimber, nimber_square, Level, Shift, Smallest,
+};
+use crate::repr::NimberRepr;
+
+// product of polynomials with coefficients at level lvl, the constant term first
+fn poly_mul<T: NimberRepr>(f: &[Nimber<T>], g: &[Nimber<T>], lvl: Level) -> Vec<Nimber<T>> {
+    let mut ans = vec![Nimber::from(T::from(0 as Smallest)); f.len() + g.len() - 1];
+
+    for (i, fi) in f.iter().enumerate() {
+        for (j, gj) in g.iter().enumerate() {
+            ans[i + j] += nimber_mul_nimber::<T>(fi, gj, lvl);
+        }
+    }
+
+    ans
+}
+
+impl<T: NimberRepr> Nimber<T> {
+    /// Returns the degree of a nimber over GF(2),
+    /// the degree `2^k` of the smallest subfield containing it, the nimbers below `2^(2^k)`.
+    ///
+    /// The complexity is *O*(log *n*).
+    #[inline]
+    pub fn degree(&self) -> usize {
+        (1 as Shift) << level::<T>(self)
+    }
+
+    /// Returns the conjugates of a nimber over GF(2), its orbit under the Frobenius map:
+    /// `x, x^2, x^4, ..., x^(2^(d - 1))` for the [`degree`](Nimber::degree) `d`.
+    ///
+    /// The conjugates are pairwise distinct.
+    ///
+    /// The complexity is *O*(*d*^(1 + log_2 3)).
+    pub fn conjugates(&self) -> Vec<Nimber<T>> {
+        let lvl = level::<T>(self);
+        let mut ans = vec![self.clone()];
+
+        for _ in 1..(1 as Shift) << lvl {
+            let next = nimber_square::<T>(&ans[ans.len() - 1], lvl);
+            ans.push(next);
+        }
+
+        ans
+    }
+
+    /// Returns the minimal polynomial of a nimber over GF(2),
+    /// the product of `x + c` over its [`conjugates`](Nimber::conjugates) `c`.
+    ///
+    /// The coefficients are listed from the constant term up to the leading one,
+    /// so the vector has [`degree`](Nimber::degree) `+ 1` elements and ends with `true`.
+    ///
+    /// The complexity is *O*(*d*^2).
+    pub fn minimal_polynomial(&self) -> Vec<bool> {
+        let one = Nimber::from(T::from(1 as Smallest));
+        let mut poly = vec![self.clone(), one.clone()];
+
+        for lvl in (0..level::<T>(self)).rev() {
+            // c = ch X + cl is mapped to ch (X + 1) + cl
+            let conj: Vec<_> = poly
+                .iter()
+                .map(|c| {
+                    let mut c = c.clone();
+                    c += high_part::<T>(&c, lvl);
+                    c
+                })
+                .collect();
+
+            poly = poly_mul::<T>(&poly, &conj, lvl + 1);
+        }
+
+        poly.iter().map(|c| *c == one).collect()
+    }
+}
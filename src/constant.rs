@@ -0,0 +1,224 @@
+// Const fn arithmetic of the fixed-width nimbers
+// The same recursions as in multiplication.rs, written on the primitive types
+// so that they can be evaluated at compile time; Mul of these types delegates here
+
+use super::Nimber;
+use crate::multiplication::Level;
+
+#[cfg(feature = "tables")]
+use crate::table::{
+    table_inverse_u16, table_mul_u16, table_sqrt_u16, table_square_u16, TABLE_LEVEL,
+};
+
+macro_rules! nimber_const {
+    ($($t:ty),*) => {
+        $(
+            impl Nimber<$t> {
+                /// The additive identity, `0`.
+                pub const ZERO: Self = Nimber { x: 0 };
+
+                /// The multiplicative identity, `1`.
+                pub const ONE: Self = Nimber { x: 1 };
+
+                /// Creates a nimber, the same as `Nimber::from` in const contexts.
+                #[inline]
+                pub const fn new(x: $t) -> Self {
+                    Nimber { x }
+                }
+
+                /// Returns the underlying integer, the same as `unwrap` in const contexts.
+                #[inline]
+                pub const fn get(self) -> $t {
+                    self.x
+                }
+
+                /// Adds two nimbers, the same as `+` in const contexts.
+                #[inline]
+                pub const fn const_add(self, rhs: Self) -> Self {
+                    Nimber { x: self.x ^ rhs.x }
+                }
+
+                /// Multiplies two nimbers, the same as `*` in const contexts.
+                ///
+                /// The complexity is *O*(*n*^(log_2 3)).
+                #[inline]
+                pub const fn const_mul(self, rhs: Self) -> Self {
+                    Nimber {
+                        x: Self::mul_at(self.x, rhs.x, Self::level_of(self.x | rhs.x)),
+                    }
+                }
+
+                /// Squares a nimber, the same as [`square`](Nimber::square) in const contexts.
+                ///
+                /// The complexity is *O*(*n*^(log_2 3)).
+                #[inline]
+                pub const fn const_square(self) -> Self {
+                    Nimber {
+                        x: Self::square_at(self.x, Self::level_of(self.x)),
+                    }
+                }
+
+                /// Returns the square root of a nimber,
+                /// the same as [`sqrt`](Nimber::sqrt) in const contexts.
+                ///
+                /// The complexity is *O*(*n*^(log_2 3)).
+                #[inline]
+                pub const fn const_sqrt(self) -> Self {
+                    Nimber {
+                        x: Self::sqrt_at(self.x, Self::level_of(self.x)),
+                    }
+                }
+
+                /// Takes the reciprocal of a nimber,
+                /// the same as [`recip`](Nimber::recip) in const contexts.
+                ///
+                /// The complexity is *O*(*n*^(log_2 3) \* log *n*).
+                ///
+                /// # Panics
+                ///
+                /// Panics if `self` is zero.
+                #[inline]
+                pub const fn const_recip(self) -> Self {
+                    assert!(self.x != 0, "attempt to divide by zero");
+
+                    Nimber {
+                        x: Self::inverse_at(self.x, Self::level_of(self.x)),
+                    }
+                }
+
+                // the smallest level at which a fits
+                const fn level_of(a: $t) -> Level {
+                    let bits = <$t>::BITS - a.leading_zeros();
+
+                    if bits <= 1 {
+                        0
+                    } else {
+                        (u32::BITS - (bits - 1).leading_zeros()) as Level
+                    }
+                }
+
+                const fn halves(a: $t, lvl: Level) -> ($t, $t) {
+                    let half = 1u32 << lvl;
+
+                    (a >> half, a & ((1 << half) - 1))
+                }
+
+                const fn join_halves(high: $t, low: $t, lvl: Level) -> $t {
+                    (high << (1u32 << lvl)) | low
+                }
+
+                // multiply by 1 << ((1 << lvl) - 1)
+                const fn mul_fermat_at(a: $t, lvl: Level) -> $t {
+                    #[cfg(feature = "tables")]
+                    if lvl <= TABLE_LEVEL {
+                        return table_mul_u16(a as u16, 1 << ((1 << lvl) - 1), lvl) as $t;
+                    }
+
+                    if lvl == 0 {
+                        return a;
+                    }
+
+                    let lvl = lvl - 1;
+                    let (ah, al) = Self::halves(a, lvl);
+
+                    Self::join_halves(
+                        Self::mul_fermat_at(ah ^ al, lvl),
+                        Self::mul_fermat_at(Self::mul_fermat_at(ah, lvl), lvl),
+                        lvl,
+                    )
+                }
+
+                const fn mul_at(a: $t, b: $t, lvl: Level) -> $t {
+                    #[cfg(feature = "tables")]
+                    if lvl <= TABLE_LEVEL {
+                        return table_mul_u16(a as u16, b as u16, lvl) as $t;
+                    }
+
+                    if lvl == 0 {
+                        return a & b;
+                    }
+
+                    let lvl = lvl - 1;
+                    let (ah, al) = Self::halves(a, lvl);
+                    let (bh, bl) = Self::halves(b, lvl);
+
+                    let low_mul = Self::mul_at(al, bl, lvl);
+
+                    Self::join_halves(
+                        Self::mul_at(ah ^ al, bh ^ bl, lvl) ^ low_mul,
+                        Self::mul_fermat_at(Self::mul_at(ah, bh, lvl), lvl) ^ low_mul,
+                        lvl,
+                    )
+                }
+
+                const fn square_at(a: $t, lvl: Level) -> $t {
+                    #[cfg(feature = "tables")]
+                    if lvl <= TABLE_LEVEL {
+                        return table_square_u16(a as u16, lvl) as $t;
+                    }
+
+                    if lvl == 0 {
+                        return a;
+                    }
+
+                    let lvl = lvl - 1;
+                    let (ah, al) = Self::halves(a, lvl);
+                    let ah = Self::square_at(ah, lvl);
+
+                    Self::join_halves(
+                        ah,
+                        Self::square_at(al, lvl) ^ Self::mul_fermat_at(ah, lvl),
+                        lvl,
+                    )
+                }
+
+                const fn sqrt_at(a: $t, lvl: Level) -> $t {
+                    #[cfg(feature = "tables")]
+                    if lvl <= TABLE_LEVEL {
+                        return table_sqrt_u16(a as u16, lvl) as $t;
+                    }
+
+                    if lvl == 0 {
+                        return a;
+                    }
+
+                    let lvl = lvl - 1;
+                    let (ah, al) = Self::halves(a, lvl);
+
+                    Self::join_halves(
+                        Self::sqrt_at(ah, lvl),
+                        Self::sqrt_at(al ^ Self::mul_fermat_at(ah, lvl), lvl),
+                        lvl,
+                    )
+                }
+
+                const fn inverse_at(a: $t, lvl: Level) -> $t {
+                    #[cfg(feature = "tables")]
+                    if lvl <= TABLE_LEVEL {
+                        return table_inverse_u16(a as u16, lvl) as $t;
+                    }
+
+                    if lvl == 0 {
+                        return a;
+                    }
+
+                    let lvl = lvl - 1;
+                    let (ah, al) = Self::halves(a, lvl);
+                    let asum = ah ^ al;
+
+                    let det = Self::mul_at(asum, al, lvl)
+                        ^ Self::mul_fermat_at(Self::square_at(ah, lvl), lvl);
+                    let det = Self::inverse_at(det, lvl);
+
+                    Self::join_halves(
+                        Self::mul_at(ah, det, lvl),
+                        Self::mul_at(asum, det, lvl),
+                        lvl,
+                    )
+                }
+            }
+        )*
+    };
+}
+
+nimber_const!(u8, u16, u32, u64, u128, usize);
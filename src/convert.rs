@@ -0,0 +1,71 @@
+// Conversions between nimbers of different widths
+// Nimbers below 2^(2^k) form a subfield, so zero extension is a field embedding,
+// and narrowing succeeds exactly when the nimber lies in the smaller subfield
+
+use super::{Nimber, NimberError};
+use std::convert::TryFrom;
+
+#[cfg(feature = "num-bigint")]
+use num_bigint::BigUint;
+
+macro_rules! nimber_widen {
+    ($($from:ty => $($to:ty),*);*) => {
+        $($(
+            /// Embeds a nimber into a wider field, which preserves all the field operations.
+            impl From<Nimber<$from>> for Nimber<$to> {
+                #[inline]
+                fn from(a: Nimber<$from>) -> Self {
+                    Nimber::from(<$to>::from(a.x))
+                }
+            }
+        )*)*
+    };
+}
+
+macro_rules! nimber_narrow {
+    ($($from:ty => $($to:ty),*);*) => {
+        $($(
+            /// Converts a nimber to a narrower field,
+            /// failing with [`NimberError::NotInSubfield`] if it does not fit.
+            impl TryFrom<Nimber<$from>> for Nimber<$to> {
+                type Error = NimberError;
+
+                #[inline]
+                fn try_from(a: Nimber<$from>) -> Result<Self, Self::Error> {
+                    <$to>::try_from(a.x)
+                        .map(Nimber::from)
+                        .map_err(|_| NimberError::NotInSubfield)
+                }
+            }
+        )*)*
+    };
+}
+
+// the same pairs as the integer conversions of the standard library
+nimber_widen!(
+    u8 => u16, u32, u64, u128, usize;
+    u16 => u32, u64, u128, usize;
+    u32 => u64, u128;
+    u64 => u128
+);
+
+nimber_narrow!(
+    u16 => u8;
+    u32 => u8, u16, usize;
+    u64 => u8, u16, u32, usize;
+    u128 => u8, u16, u32, u64, usize;
+    usize => u8, u16, u32, u64, u128
+);
+
+#[cfg(feature = "num-bigint")]
+nimber_widen!(
+    u8 => BigUint;
+    u16 => BigUint;
+    u32 => BigUint;
+    u64 => BigUint;
+    u128 => BigUint;
+    usize => BigUint
+);
+
+#[cfg(feature = "num-bigint")]
+nimber_narrow!(BigUint => u8, u16, u32, u64, u128, usize);
@@ -1,7 +1,7 @@
 use super::Nimber;
 
 use std::cmp::Ordering;
-use std::fmt::{Debug, Formatter};
+use std::fmt::{Binary, Debug, Display, Formatter, LowerHex, Octal, UpperHex};
 use std::hash::{Hash, Hasher};
 
 impl<T: Clone> Clone for Nimber<T> {
@@ -25,6 +25,34 @@ impl<T: Debug> Debug for Nimber<T> {
     }
 }
 
+/// Formats the value of a nimber like the underlying integer, `5`.
+/// The alternate form `{:#}` uses the star notation of combinatorial game theory, `*5`.
+impl<T: Display> Display for Nimber<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            f.pad_integral(true, "*", &self.x.to_string())
+        } else {
+            Display::fmt(&self.x, f)
+        }
+    }
+}
+
+macro_rules! nimber_forward_fmt {
+    ($($imp:ident),*) => {
+        $(
+            impl<T: $imp> $imp for Nimber<T> {
+                #[inline]
+                fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                    $imp::fmt(&self.x, f)
+                }
+            }
+        )*
+    };
+}
+
+nimber_forward_fmt!(LowerHex, UpperHex, Binary, Octal);
+
 impl<T: Default> Default for Nimber<T> {
     #[inline]
     fn default() -> Self {
@@ -54,6 +82,7 @@ impl<T: Hash> Hash for Nimber<T> {
     }
 }
 
+#[allow(clippy::partialeq_ne_impl)]
 impl<T: PartialEq> PartialEq for Nimber<T> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
@@ -0,0 +1,409 @@
+// Polynomial equations over nimbers
+// In characteristic 2 the quadratic formula does not work,
+// instead a x^2 + b x + c = 0 is reduced to the Artin-Schreier equation y^2 + y = a c / b^2
+// Cubics are solved by the characteristic 2 analogue of Cardano's formula
+// and quartics are reduced to cubics through their linearized (additive) part;
+// when a polynomial has a single root, it is found as gcd(f, x^q - x)
+
+use super::Nimber;
+use crate::multiplication::{
+    nimber_inverse, nimber_mul_nimber, nimber_sqrt, nimber_square, Level, Shift, Smallest,
+};
+use crate::repr::{field_level, NimberRepr};
+use crate::root::nimber_cbrt;
+use crate::trace::nimber_artin_schreier;
+
+// polynomials are stored as vectors of coefficients, the constant term first
+impl<T: NimberRepr> Nimber<T> {
+    #[inline]
+    fn sum(a: &Nimber<T>, b: &Nimber<T>) -> Nimber<T> {
+        let mut ans = a.clone();
+        ans += b;
+        ans
+    }
+
+    fn push_root(roots: &mut Vec<Nimber<T>>, x: Nimber<T>) {
+        if !roots.contains(&x) {
+            roots.push(x);
+        }
+    }
+
+    fn trim(f: &mut Vec<Nimber<T>>) {
+        let zero = Nimber::from(T::from(0 as Smallest));
+
+        while f.last() == Some(&zero) {
+            f.pop();
+        }
+    }
+
+    // remainder of f modulo a nonzero trimmed g
+    fn poly_rem(mut f: Vec<Nimber<T>>, g: &[Nimber<T>], lvl: Level) -> Vec<Nimber<T>> {
+        let n = g.len() - 1;
+        let lead_inv = nimber_inverse::<T>(&g[n], lvl);
+
+        Self::trim(&mut f);
+
+        while f.len() > n {
+            let shift = f.len() - 1 - n;
+            let coef = nimber_mul_nimber::<T>(&f[f.len() - 1], &lead_inv, lvl);
+
+            for (j, gj) in g.iter().enumerate() {
+                f[shift + j] += nimber_mul_nimber::<T>(&coef, gj, lvl);
+            }
+
+            Self::trim(&mut f);
+        }
+
+        f
+    }
+
+    // the only root of f, if gcd(f, x^q - x) is linear, where q is the size of the field
+    fn frobenius_root(f: &[Nimber<T>], lvl: Level) -> Option<Nimber<T>> {
+        let zero = Nimber::from(T::from(0 as Smallest));
+        let one = Nimber::from(T::from(1 as Smallest));
+
+        // x^q mod f by 2^lvl squarings of x
+        let mut h = Self::poly_rem(vec![zero.clone(), one.clone()], f, lvl);
+
+        for _ in 0..(1 as Shift) << lvl {
+            let mut sq = vec![zero.clone(); 2 * h.len()];
+
+            for (i, hi) in h.iter().enumerate() {
+                sq[2 * i] = nimber_square::<T>(hi, lvl);
+            }
+
+            h = Self::poly_rem(sq, f, lvl);
+        }
+
+        h.resize(h.len().max(2), zero);
+        h[1] += &one;
+        Self::trim(&mut h);
+
+        let mut a = f.to_vec();
+
+        while !h.is_empty() {
+            let r = Self::poly_rem(a, &h, lvl);
+            a = h;
+            h = r;
+        }
+
+        if a.len() == 2 {
+            Some(nimber_mul_nimber::<T>(
+                &a[0],
+                &nimber_inverse::<T>(&a[1], lvl),
+                lvl,
+            ))
+        } else {
+            None
+        }
+    }
+
+    fn quadratic_roots(a: &Nimber<T>, b: &Nimber<T>, c: &Nimber<T>, lvl: Level) -> Vec<Nimber<T>> {
+        let zero = Nimber::from(T::from(0 as Smallest));
+
+        if *a == zero {
+            return if *b == zero {
+                Vec::new()
+            } else {
+                vec![nimber_mul_nimber::<T>(c, &nimber_inverse::<T>(b, lvl), lvl)]
+            };
+        }
+
+        let a_inv = nimber_inverse::<T>(a, lvl);
+
+        // x^2 = c / a
+        if *b == zero {
+            return vec![nimber_sqrt::<T>(
+                &nimber_mul_nimber::<T>(c, &a_inv, lvl),
+                lvl,
+            )];
+        }
+
+        // x = b y / a turns the equation into y^2 + y = a c / b^2
+        let b_inv = nimber_inverse::<T>(b, lvl);
+        let rhs = nimber_mul_nimber::<T>(
+            &nimber_mul_nimber::<T>(a, c, lvl),
+            &nimber_mul_nimber::<T>(&b_inv, &b_inv, lvl),
+            lvl,
+        );
+        let scale = nimber_mul_nimber::<T>(b, &a_inv, lvl);
+
+        match nimber_artin_schreier::<T>(&rhs, lvl) {
+            Some((y0, y1)) => vec![
+                nimber_mul_nimber::<T>(&y0, &scale, lvl),
+                nimber_mul_nimber::<T>(&y1, &scale, lvl),
+            ],
+            None => Vec::new(),
+        }
+    }
+
+    // distinct roots of x^3 + b x^2 + c x + d
+    fn monic_cubic_roots(
+        b: &Nimber<T>,
+        c: &Nimber<T>,
+        d: &Nimber<T>,
+        lvl: Level,
+    ) -> Vec<Nimber<T>> {
+        let zero = Nimber::from(T::from(0 as Smallest));
+        let one = Nimber::from(T::from(1 as Smallest));
+
+        if *d == zero {
+            let mut roots = vec![zero];
+
+            for x in Self::quadratic_roots(&one, b, c, lvl) {
+                Self::push_root(&mut roots, x);
+            }
+
+            return roots;
+        }
+
+        // x = y + b turns the equation into y^3 + p y + r = 0
+        let p = Self::sum(&nimber_square::<T>(b, lvl), c);
+        let r = Self::sum(&nimber_mul_nimber::<T>(b, c, lvl), d);
+
+        let ys = if r == zero {
+            vec![zero.clone(), nimber_sqrt::<T>(&p, lvl)]
+        } else if p == zero {
+            nimber_cbrt::<T>(&r, lvl)
+        } else {
+            // y = z + p / z gives z^6 + r z^3 + p^3 = 0, and z^3 = r t gives t^2 + t = p^3 / r^2
+            let r_inv = nimber_inverse::<T>(&r, lvl);
+            let delta = nimber_mul_nimber::<T>(
+                &nimber_mul_nimber::<T>(&p, &nimber_square::<T>(&p, lvl), lvl),
+                &nimber_square::<T>(&r_inv, lvl),
+                lvl,
+            );
+
+            match nimber_artin_schreier::<T>(&delta, lvl) {
+                Some((t, _)) => nimber_cbrt::<T>(&nimber_mul_nimber::<T>(&r, &t, lvl), lvl)
+                    .iter()
+                    .map(|z| {
+                        Self::sum(
+                            z,
+                            &nimber_mul_nimber::<T>(&p, &nimber_inverse::<T>(z, lvl), lvl),
+                        )
+                    })
+                    .collect(),
+                // t lies in the quadratic extension, and then the cubic has exactly one root
+                None => Self::frobenius_root(&[r, p, zero.clone(), one], lvl)
+                    .into_iter()
+                    .collect(),
+            }
+        };
+
+        let mut roots = Vec::new();
+
+        for y in ys {
+            Self::push_root(&mut roots, Self::sum(&y, b));
+        }
+
+        roots
+    }
+
+    fn cubic_roots(
+        a: &Nimber<T>,
+        b: &Nimber<T>,
+        c: &Nimber<T>,
+        d: &Nimber<T>,
+        lvl: Level,
+    ) -> Vec<Nimber<T>> {
+        if *a == Nimber::from(T::from(0 as Smallest)) {
+            return Self::quadratic_roots(b, c, d, lvl);
+        }
+
+        let a_inv = nimber_inverse::<T>(a, lvl);
+
+        Self::monic_cubic_roots(
+            &nimber_mul_nimber::<T>(b, &a_inv, lvl),
+            &nimber_mul_nimber::<T>(c, &a_inv, lvl),
+            &nimber_mul_nimber::<T>(d, &a_inv, lvl),
+            lvl,
+        )
+    }
+
+    // distinct roots of z^4 + p z^2 + q z + r
+    fn affine_quartic_roots(
+        p: &Nimber<T>,
+        q: &Nimber<T>,
+        r: &Nimber<T>,
+        lvl: Level,
+    ) -> Vec<Nimber<T>> {
+        let zero = Nimber::from(T::from(0 as Smallest));
+        let one = Nimber::from(T::from(1 as Smallest));
+
+        // a square of z^2 + sqrt(p) z + sqrt(r)
+        if *q == zero {
+            return Self::quadratic_roots(
+                &one,
+                &nimber_sqrt::<T>(p, lvl),
+                &nimber_sqrt::<T>(r, lvl),
+                lvl,
+            );
+        }
+
+        // (z^2 + u z + v) (z^2 + u z + v') with u^3 + p u + q = 0, v + v' = q / u and v v' = r
+        let us = Self::monic_cubic_roots(&zero, p, q, lvl);
+
+        let u = match us.first() {
+            Some(u) => u.clone(),
+            // z^4 + p z^2 + q z is then a bijection, so there is exactly one root
+            None => {
+                return Self::frobenius_root(&[r.clone(), q.clone(), p.clone(), zero, one], lvl)
+                    .into_iter()
+                    .collect()
+            }
+        };
+
+        let s = nimber_mul_nimber::<T>(q, &nimber_inverse::<T>(&u, lvl), lvl);
+        let mut roots = Vec::new();
+
+        for v in Self::quadratic_roots(&one, &s, r, lvl) {
+            for z in Self::quadratic_roots(&one, &u, &v, lvl) {
+                Self::push_root(&mut roots, z);
+            }
+        }
+
+        roots
+    }
+
+    // distinct roots of x^4 + b x^3 + c x^2 + d x + e
+    fn monic_quartic_roots(
+        b: &Nimber<T>,
+        c: &Nimber<T>,
+        d: &Nimber<T>,
+        e: &Nimber<T>,
+        lvl: Level,
+    ) -> Vec<Nimber<T>> {
+        let zero = Nimber::from(T::from(0 as Smallest));
+        let one = Nimber::from(T::from(1 as Smallest));
+
+        if *e == zero {
+            let mut roots = vec![zero];
+
+            for x in Self::monic_cubic_roots(b, c, d, lvl) {
+                Self::push_root(&mut roots, x);
+            }
+
+            return roots;
+        }
+
+        if *b == zero {
+            return Self::affine_quartic_roots(c, d, e, lvl);
+        }
+
+        // x = y + t with b t^2 = d removes the linear term: y^4 + b y^3 + c' y^2 + e'
+        let t = nimber_sqrt::<T>(
+            &nimber_mul_nimber::<T>(d, &nimber_inverse::<T>(b, lvl), lvl),
+            lvl,
+        );
+        let c1 = Self::sum(&nimber_mul_nimber::<T>(b, &t, lvl), c);
+        let e1 = [b, c, d, e].iter().fold(one.clone(), |acc, coef| {
+            Self::sum(&nimber_mul_nimber::<T>(&acc, &t, lvl), coef)
+        });
+
+        let ys = if e1 == zero {
+            let mut ys = vec![zero];
+            ys.extend(Self::quadratic_roots(&one, b, &c1, lvl));
+            ys
+        } else {
+            // y = 1 / z gives z^4 + (c' / e') z^2 + (b / e') z + 1 / e'
+            let e1_inv = nimber_inverse::<T>(&e1, lvl);
+
+            Self::affine_quartic_roots(
+                &nimber_mul_nimber::<T>(&c1, &e1_inv, lvl),
+                &nimber_mul_nimber::<T>(b, &e1_inv, lvl),
+                &e1_inv,
+                lvl,
+            )
+            .iter()
+            .map(|z| nimber_inverse::<T>(z, lvl))
+            .collect()
+        };
+
+        let mut roots = Vec::new();
+
+        for y in ys {
+            Self::push_root(&mut roots, Self::sum(&y, &t));
+        }
+
+        roots
+    }
+}
+
+/// Finds all roots of the quadratic equation `a x^2 + b x + c = 0`.
+///
+/// The roots are searched for in the whole field of the type,
+/// or in the smallest subfield containing the coefficients for unbounded nimbers.
+/// Returns two distinct roots, one double root if `b` is zero, or none.
+///
+/// If `a` is zero, the root of the linear equation `b x + c = 0` is returned.
+/// The zero polynomial is reported as having no roots.
+///
+/// The complexity is *O*(*n*^(log_2 3) \* log *n*).
+#[inline]
+pub fn solve_quadratic<T: NimberRepr>(
+    a: &Nimber<T>,
+    b: &Nimber<T>,
+    c: &Nimber<T>,
+) -> Vec<Nimber<T>> {
+    let lvl = field_level::<T>(&(Nimber::from(a.x.bitor_ref(&b.x)) | c));
+
+    Nimber::quadratic_roots(a, b, c, lvl)
+}
+
+/// Finds all distinct roots of the cubic equation `a x^3 + b x^2 + c x + d = 0`.
+///
+/// The field is the same as in [`solve_quadratic`].
+/// If `a` is zero, the equation is solved as a quadratic one.
+///
+/// The complexity is *O*(*n*^(1 + log_2 3) \* log *n*).
+///
+/// # Panics
+///
+/// Panics if the field does not fit in 128 bits.
+#[inline]
+pub fn solve_cubic<T: NimberRepr>(
+    a: &Nimber<T>,
+    b: &Nimber<T>,
+    c: &Nimber<T>,
+    d: &Nimber<T>,
+) -> Vec<Nimber<T>> {
+    let lvl = field_level::<T>(&(Nimber::from(a.x.bitor_ref(&b.x)) | c | d));
+
+    Nimber::cubic_roots(a, b, c, d, lvl)
+}
+
+/// Finds all distinct roots of the quartic equation `a x^4 + b x^3 + c x^2 + d x + e = 0`.
+///
+/// The field is the same as in [`solve_quadratic`].
+/// If `a` is zero, the equation is solved as a cubic one.
+///
+/// The complexity is *O*(*n*^(1 + log_2 3) \* log *n*).
+///
+/// # Panics
+///
+/// Panics if the field does not fit in 128 bits.
+#[inline]
+pub fn solve_quartic<T: NimberRepr>(
+    a: &Nimber<T>,
+    b: &Nimber<T>,
+    c: &Nimber<T>,
+    d: &Nimber<T>,
+    e: &Nimber<T>,
+) -> Vec<Nimber<T>> {
+    let lvl = field_level::<T>(&(Nimber::from(a.x.bitor_ref(&b.x)) | c | d | e));
+
+    if *a == Nimber::from(T::from(0 as Smallest)) {
+        return Nimber::cubic_roots(b, c, d, e, lvl);
+    }
+
+    let a_inv = nimber_inverse::<T>(a, lvl);
+
+    Nimber::monic_quartic_roots(
+        &nimber_mul_nimber::<T>(b, &a_inv, lvl),
+        &nimber_mul_nimber::<T>(c, &a_inv, lvl),
+        &nimber_mul_nimber::<T>(d, &a_inv, lvl),
+        &nimber_mul_nimber::<T>(e, &a_inv, lvl),
+        lvl,
+    )
+}
@@ -0,0 +1,26 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// An error returned by the fallible nimber operations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NimberError {
+    /// Division by zero or the reciprocal of zero.
+    DivisionByZero,
+    /// The element does not lie in the requested subfield.
+    NotInSubfield,
+    /// The string does not represent a nimber.
+    Parse,
+}
+
+impl Display for NimberError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            NimberError::DivisionByZero => "attempt to divide by zero",
+            NimberError::NotInSubfield => "nimber does not lie in the requested subfield",
+            NimberError::Parse => "invalid nimber literal",
+        })
+    }
+}
+
+impl Error for NimberError {}
@@ -0,0 +1,76 @@
+// Implementation of ff::Field for the fixed-width fields
+// The field has characteristic 2, so doubling is zero and every element is a square,
+// which makes sqrt_ratio a plain square root of the quotient
+
+use super::Nimber;
+use ff::Field;
+use rand_core::RngCore;
+use std::mem::size_of;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+macro_rules! nimber_ff {
+    ($($t:ty),*) => {
+        $(
+            impl ConstantTimeEq for Nimber<$t> {
+                #[inline]
+                fn ct_eq(&self, other: &Self) -> Choice {
+                    self.x.ct_eq(&other.x)
+                }
+            }
+
+            impl ConditionallySelectable for Nimber<$t> {
+                #[inline]
+                fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                    Nimber::from(<$t>::conditional_select(&a.x, &b.x, choice))
+                }
+            }
+
+            /// The field operations are not constant-time:
+            /// only equality and selection are.
+            impl Field for Nimber<$t> {
+                const ZERO: Self = Nimber { x: 0 };
+                const ONE: Self = Nimber { x: 1 };
+
+                /// Returns a uniformly random nimber.
+                #[inline]
+                fn random(mut rng: impl RngCore) -> Self {
+                    let mut bytes = [0; size_of::<$t>()];
+                    rng.fill_bytes(&mut bytes);
+
+                    Nimber::from(<$t>::from_le_bytes(bytes))
+                }
+
+                #[inline]
+                fn square(&self) -> Self {
+                    Nimber::<$t>::square(self)
+                }
+
+                /// Returns zero, since the field has characteristic 2.
+                #[inline]
+                fn double(&self) -> Self {
+                    Self::ZERO
+                }
+
+                #[inline]
+                fn invert(&self) -> CtOption<Self> {
+                    match self.checked_recip() {
+                        Ok(inverse) => CtOption::new(inverse, Choice::from(1)),
+                        Err(_) => CtOption::new(Self::ZERO, Choice::from(0)),
+                    }
+                }
+
+                /// Every nimber is a square, so the result is `(true, sqrt(num / div))`,
+                /// or `(true, 0)` if `num` is zero, or `(false, 0)` if only `div` is zero.
+                #[inline]
+                fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+                    match div.checked_recip() {
+                        Ok(inverse) => (Choice::from(1), (num * inverse).sqrt()),
+                        Err(_) => (num.ct_eq(&Self::ZERO), Self::ZERO),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+nimber_ff!(u8, u16, u32, u64, u128);
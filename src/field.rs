@@ -0,0 +1,203 @@
+// Fields of nimbers
+// The nimbers below 2^BITS form the field GF(2^BITS), so every fixed-width type
+// gives a finite field; the unbounded BigNim is the union of all of them, an infinite field,
+// hence the constants which only make sense for a finite field are Options
+
+use super::{Nimber, NimberError};
+use crate::multiplication::Level;
+#[cfg(feature = "num-bigint")]
+use num_bigint::BigUint;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Fields of nimbers: the finite fields [`Nim8`](crate::Nim8), [`Nim16`](crate::Nim16),
+/// [`Nim32`](crate::Nim32), [`Nim64`](crate::Nim64) and [`Nim128`](crate::Nim128),
+/// and the infinite field `BigNim` with the `num-bigint` feature.
+///
+/// Field-generic code, such as polynomial or matrix arithmetic, can be written
+/// with the single bound `F: NimberField`. `BigNim` is neither `Copy` nor has
+/// a constant `ONE`, so the trait only requires `Clone` and gives the identities
+/// by [`field_zero`](NimberField::field_zero) and [`field_one`](NimberField::field_one);
+/// code for the finite fields only can use [`FiniteNimberField`] and its constants.
+pub trait NimberField:
+    Clone
+    + Eq
+    + Ord
+    + Hash
+    + Debug
+    + Default
+    + Add<Output = Self>
+    + for<'a> Add<&'a Self, Output = Self>
+    + AddAssign
+    + for<'a> AddAssign<&'a Self>
+    + Sub<Output = Self>
+    + for<'a> Sub<&'a Self, Output = Self>
+    + SubAssign
+    + for<'a> SubAssign<&'a Self>
+    + Mul<Output = Self>
+    + for<'a> Mul<&'a Self, Output = Self>
+    + MulAssign
+    + for<'a> MulAssign<&'a Self>
+    + Div<Output = Self>
+    + for<'a> Div<&'a Self, Output = Self>
+    + DivAssign
+    + for<'a> DivAssign<&'a Self>
+    + Neg<Output = Self>
+{
+    /// The integer type holding the bits of a nimber.
+    type Bits: Clone;
+
+    /// The number of bits, the degree of the field over GF(2),
+    /// or `None` for an infinite field.
+    const BITS: Option<u32>;
+
+    /// The level of the field in the tower, `log_2 BITS`,
+    /// or `None` for an infinite field.
+    const LEVEL: Option<Level>;
+
+    /// The order of the multiplicative group, `2^BITS - 1`,
+    /// which every [`multiplicative_order`](Nimber::multiplicative_order) divides,
+    /// or `None` for an infinite field.
+    const ORDER: Option<u128>;
+
+    /// Returns the additive identity, `0`.
+    fn field_zero() -> Self;
+
+    /// Returns the multiplicative identity, `1`.
+    fn field_one() -> Self;
+
+    /// Creates a nimber from its bits.
+    fn from_bits(bits: Self::Bits) -> Self;
+
+    /// Returns the bits of a nimber.
+    fn to_bits(self) -> Self::Bits;
+
+    /// See [`Nimber::recip`].
+    fn recip(&self) -> Self;
+
+    /// See [`Nimber::checked_recip`].
+    fn checked_recip(&self) -> Result<Self, NimberError>;
+
+    /// See [`Nimber::checked_div`].
+    fn checked_div(&self, rhs: &Self) -> Result<Self, NimberError>;
+
+    /// See [`Nimber::square`].
+    fn square(&self) -> Self;
+
+    /// See [`Nimber::sqrt`].
+    fn sqrt(&self) -> Self;
+
+    /// See [`Nimber::pow`].
+    fn pow(&self, exp: u64) -> Self;
+
+    /// See [`Nimber::pow_u128`].
+    fn pow_u128(&self, exp: u128) -> Self;
+}
+
+/// The finite fields of nimbers, the fixed-width ones, with `Copy` elements
+/// and the identities as constants.
+pub trait FiniteNimberField: NimberField + Copy {
+    /// The additive identity, `0`.
+    const ZERO: Self;
+
+    /// The multiplicative identity, `1`.
+    const ONE: Self;
+}
+
+macro_rules! nimber_field {
+    ($($t:ty),*) => {
+        $(
+            nimber_field!(
+                $t:
+                Some(<$t>::BITS),
+                Some(<$t>::BITS.trailing_zeros() as Level),
+                Some(<$t>::MAX as u128),
+                Self::ZERO,
+                Self::ONE
+            );
+
+            impl FiniteNimberField for Nimber<$t> {
+                const ZERO: Self = Nimber { x: 0 };
+                const ONE: Self = Nimber { x: 1 };
+            }
+        )*
+    };
+    ($t:ty: $bits:expr, $level:expr, $order:expr, $zero:expr, $one:expr) => {
+        impl NimberField for Nimber<$t> {
+            type Bits = $t;
+
+            const BITS: Option<u32> = $bits;
+            const LEVEL: Option<Level> = $level;
+            const ORDER: Option<u128> = $order;
+
+            #[inline]
+            fn field_zero() -> Self {
+                $zero
+            }
+
+            #[inline]
+            fn field_one() -> Self {
+                $one
+            }
+
+            #[inline]
+            fn from_bits(bits: $t) -> Self {
+                Nimber::from(bits)
+            }
+
+            #[inline]
+            fn to_bits(self) -> $t {
+                self.x
+            }
+
+            #[inline]
+            fn recip(&self) -> Self {
+                Nimber::<$t>::recip(self)
+            }
+
+            #[inline]
+            fn checked_recip(&self) -> Result<Self, NimberError> {
+                Nimber::<$t>::checked_recip(self)
+            }
+
+            #[inline]
+            fn checked_div(&self, rhs: &Self) -> Result<Self, NimberError> {
+                Nimber::<$t>::checked_div(self, rhs)
+            }
+
+            #[inline]
+            fn square(&self) -> Self {
+                Nimber::<$t>::square(self)
+            }
+
+            #[inline]
+            fn sqrt(&self) -> Self {
+                Nimber::<$t>::sqrt(self)
+            }
+
+            #[inline]
+            fn pow(&self, exp: u64) -> Self {
+                Nimber::<$t>::pow(self, exp)
+            }
+
+            #[inline]
+            fn pow_u128(&self, exp: u128) -> Self {
+                Nimber::<$t>::pow_u128(self, exp)
+            }
+        }
+    };
+}
+
+nimber_field!(u8, u16, u32, u64, u128);
+
+// the union of the finite fields, so no bits, level nor order
+#[cfg(feature = "num-bigint")]
+nimber_field!(
+    BigUint:
+    None,
+    None,
+    None,
+    Nimber::from(BigUint::from(0u8)),
+    Nimber::from(BigUint::from(1u8))
+);
@@ -6,8 +6,48 @@ pub struct Nimber<T> {
 mod macros;
 
 mod addition;
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+mod clmul;
+mod conjugate;
+mod constant;
+mod convert;
 mod derive;
+mod equation;
+mod error;
+#[cfg(feature = "ff")]
+mod ff_field;
+mod field;
+mod log;
+mod mixed;
 mod multiplication;
+mod multiplier;
+#[cfg(feature = "num-traits")]
+mod num;
+mod order;
+mod parse;
+mod power;
+#[cfg(feature = "rand")]
+mod random;
+mod repr;
+mod root;
+#[cfg(feature = "serde")]
+mod serialize;
+mod slice;
+mod subfield;
+#[cfg(feature = "tables")]
+mod table;
+mod trace;
+
+pub use equation::{solve_cubic, solve_quadratic, solve_quartic};
+pub use error::NimberError;
+pub use field::{FiniteNimberField, NimberField};
+pub use multiplier::Multiplier;
+#[cfg(feature = "rand")]
+pub use random::{UniformNonzero, UniformSubfield};
+pub use repr::NimberRepr;
+#[cfg(feature = "serde")]
+pub use serialize::hex as serde_hex;
+pub use slice::{mul_add_slice, mul_slice};
 
 pub type Nim8 = Nimber<u8>;
 pub type Nim16 = Nimber<u16>;
@@ -22,6 +62,7 @@ use num_bigint::BigUint;
 pub type BigNim = Nimber<BigUint>;
 
 #[cfg(test)]
+#[allow(clippy::needless_range_loop)]
 mod tests {
     use crate::*;
 
@@ -207,4 +248,1638 @@ mod tests {
             assert_eq!(na * na.recip(), Nimber::from(1));
         }
     }
+
+    #[test]
+    fn pow() {
+        for a in u8::MIN..u8::MAX {
+            let na = Nimber::from(a);
+            let mut expected = Nimber::from(1u8);
+
+            for e in 0..600u64 {
+                assert_eq!(na.pow(e), expected);
+                assert_eq!(na.pow_u128(e as u128), expected);
+
+                expected *= na;
+            }
+        }
+    }
+
+    #[test]
+    fn pow_group_order() {
+        for a in [
+            1u128,
+            2,
+            3,
+            255,
+            256,
+            0xdead_beef,
+            u64::MAX as u128,
+            u128::MAX,
+        ] {
+            let na = Nim128::from(a);
+
+            assert_eq!(na.pow_u128(u128::MAX), Nim128::from(1));
+            assert_eq!(na.pow_u128(u128::MAX - 1), na.recip());
+        }
+
+        assert_eq!(Nim64::from(0).pow(0), Nim64::from(1));
+        assert_eq!(Nim64::from(0).pow(u64::MAX), Nim64::from(0));
+    }
+
+    #[test]
+    fn powi() {
+        for a in 1u8..u8::MAX {
+            let na = Nimber::from(a);
+
+            for e in 0..20 {
+                assert_eq!(na.powi(e), na.pow(e as u64));
+                assert_eq!(na.powi(-e), na.recip().pow(e as u64));
+                assert_eq!(na.powi(-e) * na.powi(e), Nimber::from(1));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "num-bigint")]
+    fn pow_big() {
+        for a in u8::MIN..u8::MAX {
+            for e in (0..600u64).step_by(13) {
+                assert_eq!(
+                    BigNim::from(BigUint::from(a)).pow_big(&BigUint::from(e)),
+                    BigNim::from(BigUint::from(Nimber::from(a).pow(e).unwrap()))
+                );
+            }
+        }
+
+        let huge = BigUint::from(1u8) << 1000usize;
+        let a = Nim128::from(0x1234_5678_9abc_def0_u128);
+
+        // 2^1000 = 2^(1000 mod 128) modulo 2^128 - 1
+        assert_eq!(a.pow_big(&huge), a.pow_u128(1 << (1000 % 128)));
+    }
+
+    #[test]
+    fn multiplicative_order() {
+        assert_eq!(Nim8::from(0).multiplicative_order(), None);
+
+        for a in 1u8..u8::MAX {
+            let na = Nimber::from(a);
+            let mut order = 1;
+            let mut power = na;
+
+            while power != Nimber::from(1) {
+                power *= na;
+                order += 1;
+            }
+
+            assert_eq!(na.multiplicative_order(), Some(order));
+            assert_eq!(na.pow_u128(order), Nimber::from(1));
+        }
+    }
+
+    #[test]
+    fn is_primitive() {
+        let count = (u8::MIN..=u8::MAX)
+            .filter(|&a| Nim8::from(a).is_primitive())
+            .count();
+
+        // Euler's totient of 255
+        assert_eq!(count, 128);
+        assert!(!Nim16::from(2).is_primitive());
+        assert_eq!(Nim16::from(2).multiplicative_order(), Some(3));
+    }
+
+    #[test]
+    fn primitive_element() {
+        assert!(Nim8::primitive_element().is_primitive());
+        assert!(Nim16::primitive_element().is_primitive());
+        assert!(Nim32::primitive_element().is_primitive());
+        assert!(Nim64::primitive_element().is_primitive());
+        assert!(Nim128::primitive_element().is_primitive());
+
+        assert!((0..Nim8::primitive_element().unwrap()).all(|a| !Nim8::from(a).is_primitive()));
+        assert!((0..Nim16::primitive_element().unwrap()).all(|a| !Nim16::from(a).is_primitive()));
+
+        assert_eq!(
+            Nim128::primitive_element().multiplicative_order(),
+            Some(u128::MAX)
+        );
+    }
+
+    #[test]
+    fn primitive_element_smallest() {
+        // the nimbers below 2^(BITS / 2) form the subfield of half the width,
+        // so their orders divide 2^(BITS / 2) - 1 and only the candidates above it are checked
+        for a in [0u32, 1, 2, 0xffff] {
+            let a = Nim32::from(a);
+            assert_eq!(a.pow(1 << 16), a);
+        }
+        for a in 1 << 16..Nim32::primitive_element().unwrap() {
+            assert!(Nim32::from(a).multiplicative_order().unwrap() < u32::MAX as u128);
+        }
+
+        for a in [0u64, 1, 2, u32::MAX as u64] {
+            let a = Nim64::from(a);
+            assert_eq!(a.pow(1 << 32), a);
+        }
+        for a in 1 << 32..Nim64::primitive_element().unwrap() {
+            assert!(Nim64::from(a).multiplicative_order().unwrap() < u64::MAX as u128);
+        }
+
+        for a in [0u128, 1, 2, u64::MAX as u128] {
+            let a = Nim128::from(a);
+            assert_eq!(a.pow_u128(1 << 64), a);
+        }
+        for a in 1 << 64..Nim128::primitive_element().unwrap() {
+            assert!(Nim128::from(a).multiplicative_order().unwrap() < u128::MAX);
+        }
+    }
+
+    #[test]
+    fn discrete_log() {
+        for b in 1u8..=u8::MAX {
+            let base = Nim8::from(b);
+            let order = base.multiplicative_order().unwrap();
+            let mut logs = [None; 256];
+            let mut power = Nim8::from(1);
+
+            for e in 0..order {
+                logs[power.unwrap() as usize] = Some(e);
+                power *= base;
+            }
+
+            for a in u8::MIN..=u8::MAX {
+                assert_eq!(Nim8::from(a).discrete_log(&base), logs[a as usize]);
+            }
+        }
+    }
+
+    #[test]
+    fn discrete_log_wide() {
+        let exps = [0u128, 1, 2, 12345, 0xdead_beef, 0x1234_5678_9abc_def0];
+
+        let g = Nim32::primitive_element();
+        for &e in &exps {
+            let e = e % u32::MAX as u128;
+            assert_eq!(g.pow_u128(e).discrete_log(&g), Some(e));
+        }
+
+        let g = Nim64::primitive_element();
+        for &e in &exps {
+            assert_eq!(g.pow_u128(e).discrete_log(&g), Some(e));
+        }
+        assert_eq!(Nim64::from(0).discrete_log(&g), None);
+        assert_eq!(
+            Nim64::from(1 << 40).discrete_log(&Nim64::from(1 << 20)),
+            None
+        );
+
+        // skip the largest factor of F_6 to keep the test fast
+        let g = Nim128::primitive_element().pow_u128(67_280_421_310_721);
+        let order = g.multiplicative_order().unwrap();
+        for &e in &exps[3..] {
+            let target = g.pow_u128(e);
+            assert_eq!(target.discrete_log(&g), Some(e % order));
+        }
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "slow without optimizations")]
+    fn pollard_rho_large_prime() {
+        // the largest prime factor of 2^128 - 1, the one skipped above
+        let p = 67_280_421_310_721;
+        let g = Nim128::primitive_element().pow_u128(u128::MAX / p);
+        assert_eq!(g.multiplicative_order(), Some(p));
+
+        let e = 0x3d09_dead_beef;
+        assert_eq!(Nim128::pollard_rho(&g, &g.pow_u128(e), p), e);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, ignore = "slow without optimizations")]
+    fn discrete_log_full_order() {
+        let g = Nim128::primitive_element();
+
+        for e in [0x1234_5678_9abc_def0_1122_3344_5566_7788, u128::MAX - 1] {
+            assert_eq!(g.pow_u128(e).discrete_log(&g), Some(e));
+        }
+    }
+
+    #[test]
+    fn trace() {
+        for a in u8::MIN..=u8::MAX {
+            let na = Nim8::from(a);
+            let mut trace = Nim8::from(0);
+            let mut conjugate = na;
+
+            for _ in 0..8 {
+                trace += conjugate;
+                conjugate = conjugate.square();
+            }
+
+            assert_eq!(na.trace(), trace);
+
+            for b in u8::MIN..=u8::MAX {
+                let nb = Nim8::from(b);
+
+                assert_eq!((na + nb).trace(), na.trace() + nb.trace());
+            }
+        }
+
+        assert_eq!(Nim16::from(1).trace(), Nim16::from(0));
+        assert_eq!(Nim128::from(1 << 127).trace(), Nim128::from(1));
+    }
+
+    #[test]
+    fn half_trace() {
+        for a in u16::MIN..=u16::MAX {
+            let na = Nim16::from(a);
+            let h = na.half_trace();
+
+            assert_eq!(h.square() + h == na, na.trace() == Nim16::from(0));
+        }
+    }
+
+    #[test]
+    fn solve_artin_schreier() {
+        let mut solvable = 0;
+
+        for a in u8::MIN..=u8::MAX {
+            let na = Nim8::from(a);
+
+            match na.solve_artin_schreier() {
+                Some((x, y)) => {
+                    solvable += 1;
+                    assert_ne!(x, y);
+                    assert_eq!(x.square() + x, na);
+                    assert_eq!(y.square() + y, na);
+                }
+                None => {
+                    assert!((u8::MIN..=u8::MAX)
+                        .map(Nim8::from)
+                        .all(|x| x.square() + x != na));
+                }
+            }
+        }
+
+        assert_eq!(solvable, 128);
+
+        for &a in &[
+            1u128,
+            2,
+            3,
+            0xdead_beef,
+            0x1234_5678_9abc_def0_1234_5678_9abc_def0,
+        ] {
+            let na = Nim128::from(a);
+
+            if let Some((x, _)) = na.solve_artin_schreier() {
+                assert_eq!(x.square() + x, na);
+            } else {
+                assert_eq!(na.trace(), Nim128::from(1));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "num-bigint")]
+    fn bigint_artin_schreier() {
+        // unbounded nimbers are solved in the smallest subfield containing them
+        assert_eq!(
+            BigNim::from(BigUint::from(1u8)).solve_artin_schreier(),
+            None
+        );
+        assert_eq!(
+            BigNim::from(BigUint::from(3u8)).solve_artin_schreier(),
+            None
+        );
+
+        let a = BigNim::from(BigUint::from(5u8));
+        let (x, y) = a.solve_artin_schreier().unwrap();
+        assert_eq!(x.square() + &x, a);
+        assert_eq!(y.square() + &y, a);
+
+        let a = BigNim::from(BigUint::from(0x1234_5678_9abc_def0_u64) << 100usize);
+        if let Some((x, _)) = a.solve_artin_schreier() {
+            assert_eq!(x.square() + &x, a);
+        } else {
+            assert_eq!(a.trace(), BigNim::from(BigUint::from(1u8)));
+        }
+    }
+
+    #[test]
+    fn solve_quadratic() {
+        for a in 0u8..16 {
+            for b in 0u8..16 {
+                for c in 0u8..16 {
+                    let (na, nb, nc) = (Nim8::from(a), Nim8::from(b), Nim8::from(c));
+                    let mut roots = crate::solve_quadratic(&na, &nb, &nc);
+                    roots.sort();
+
+                    let expected: Vec<_> = if a == 0 && b == 0 {
+                        Vec::new()
+                    } else {
+                        (u8::MIN..=u8::MAX)
+                            .map(Nim8::from)
+                            .filter(|&x| na * x.square() + nb * x + nc == Nim8::from(0))
+                            .collect()
+                    };
+
+                    assert_eq!(roots, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn solve_quadratic_wide() {
+        let k = Nim64::from(0x0123_4567_89ab_cdef);
+
+        for &(r0, r1) in &[(0, 0), (1, 2), (0xdead_beef, 1 << 63), (u64::MAX, 42)] {
+            let (r0, r1) = (Nim64::from(r0), Nim64::from(r1));
+            let mut roots = crate::solve_quadratic(&k, &(k * (r0 + r1)), &(k * r0 * r1));
+            roots.sort();
+            roots.dedup();
+
+            let mut expected = vec![r0, r1];
+            expected.sort();
+            expected.dedup();
+
+            assert_eq!(roots, expected);
+        }
+    }
+
+    #[test]
+    fn cbrt() {
+        for a in u8::MIN..=u8::MAX {
+            let na = Nim8::from(a);
+            let roots = na.cbrt();
+
+            for x in &roots {
+                assert_eq!(x * x * x, na);
+            }
+
+            let count = (u8::MIN..=u8::MAX)
+                .map(Nim8::from)
+                .filter(|&x| x * x * x == na)
+                .count();
+
+            assert_eq!(roots.len(), count);
+            assert!(a == 0 && count == 1 || count == 3 || count == 0);
+        }
+
+        for &a in &[7u128, 0xdead_beef, u128::MAX] {
+            let cube = Nim128::from(a) * Nim128::from(a) * Nim128::from(a);
+            let roots = cube.cbrt();
+
+            assert_eq!(roots.len(), 3);
+            assert!(roots.contains(&Nim128::from(a)));
+        }
+
+        // the roots of one are searched for in the whole field, not in GF(2)
+        assert_eq!(Nim8::new(1).cbrt().len(), 3);
+        assert_eq!(Nim8::new(1).cbrt(), [1, 2, 3].map(Nim8::new));
+    }
+
+    #[test]
+    #[cfg(feature = "num-bigint")]
+    fn cbrt_big() {
+        let one = BigNim::from(BigUint::from(1u8));
+
+        assert_eq!(one.cbrt(), vec![one]);
+    }
+
+    #[test]
+    fn nth_root() {
+        for n in (1u64..=20).chain([51, 85, 254, 255, 256, 510, 1000]) {
+            for a in u8::MIN..=u8::MAX {
+                let na = Nim8::from(a);
+                let mut roots = na.nth_root(n);
+                roots.sort();
+
+                let expected: Vec<_> = (u8::MIN..=u8::MAX)
+                    .map(Nim8::from)
+                    .filter(|&x| x.pow(n) == na)
+                    .collect();
+
+                assert_eq!(roots, expected);
+
+                if let Some((root, zeta)) = na.nth_root_coset(n) {
+                    let mut coset: Vec<_> = (0..roots.len() as u64)
+                        .map(|i| root * zeta.pow(i))
+                        .collect();
+                    coset.sort();
+
+                    assert_eq!(coset, expected);
+                } else {
+                    assert!(expected.is_empty());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn nth_root_wide() {
+        let a = Nim64::from(0x0123_4567_89ab_cdef);
+
+        // 7 is coprime to 2^64 - 1
+        assert_eq!(a.pow(7).nth_root(7), vec![a]);
+
+        // gcd(6, 2^64 - 1) = 3 and gcd(10, 2^64 - 1) = 5
+        for &n in &[6u64, 10] {
+            let roots = a.pow(n).nth_root(n);
+
+            assert_eq!(roots.len() as u64, n / 2);
+            assert!(roots.contains(&a));
+
+            for x in &roots {
+                assert_eq!(x.pow(n), a.pow(n));
+            }
+        }
+
+        // 2 is a cube in GF(4), but not in the whole field of Nim64
+        assert!(Nim64::from(2).nth_root(3).is_empty());
+        assert_eq!(Nim64::from(2).nth_root_coset(3), None);
+
+        // too many roots to store: 257 * 65537 and 2^64 - 1 of them
+        let n = 257 * 65537;
+        let (root, zeta) = a.pow(n).nth_root_coset(n).unwrap();
+
+        assert_eq!(root.pow(n), a.pow(n));
+        assert_eq!(zeta.multiplicative_order(), Some(n as u128));
+
+        let (root, zeta) = Nim64::ONE.nth_root_coset(u64::MAX).unwrap();
+
+        assert_eq!(root, Nim64::ONE);
+        assert_eq!(zeta.multiplicative_order(), Some(u64::MAX as u128));
+        assert_eq!(
+            Nim64::ZERO.nth_root_coset(5),
+            Some((Nim64::ZERO, Nim64::ONE))
+        );
+    }
+
+    #[test]
+    fn trace_norm_to() {
+        for a in u16::MIN..=u16::MAX {
+            let na = Nim16::from(a);
+            let conj = na.pow(256);
+
+            assert_eq!(na.trace_to(3), na + conj);
+            assert_eq!(na.norm_to(3), na * conj);
+            assert_eq!(na.trace_to_nim8(), Nim8::from((na + conj).x as u8));
+            assert_eq!(na.norm_to_nim8(), Nim8::from((na * conj).x as u8));
+            assert_eq!(na.trace_to(0), na.trace());
+            assert_eq!(na.trace_to(4), na);
+            assert_eq!(na.norm_to(4), na);
+        }
+
+        for &a in &[1u64, 0xdead_beef, 0x0123_4567_89ab_cdef, u64::MAX] {
+            let na = Nim64::from(a);
+            let conjugates: Vec<_> = (0..4).map(|i| na.pow(1 << (16 * i))).collect();
+
+            assert_eq!(
+                na.trace_to(4),
+                conjugates.iter().fold(Nim64::from(0), |acc, &x| acc + x)
+            );
+            assert_eq!(
+                na.norm_to(4),
+                conjugates.iter().fold(Nim64::from(1), |acc, &x| acc * x)
+            );
+            assert_eq!(Nim64::from(na.norm_to_nim16().x as u64), na.norm_to(4));
+            assert_eq!(na.norm_to(0), Nim64::from(1));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "num-bigint")]
+    fn bigint_trace_norm_to() {
+        let a = BigNim::from(BigUint::from(0xdead_beef_u64));
+        let b = Nim32::from(0xdead_beef);
+
+        assert_eq!(a.trace_to(4).x, BigUint::from(b.trace_to(4).x));
+        assert_eq!(a.norm_to(4).x, BigUint::from(b.norm_to(4).x));
+        assert_eq!(a.norm_to(7), a);
+    }
+
+    #[test]
+    fn convert() {
+        use std::convert::TryFrom;
+
+        for a in u8::MIN..=u8::MAX {
+            for b in u8::MIN..=u8::MAX {
+                let (na, nb) = (Nim8::from(a), Nim8::from(b));
+
+                assert_eq!(Nim16::from(na * nb), Nim16::from(na) * Nim16::from(nb));
+                assert_eq!(Nim128::from(na * nb), Nim128::from(na) * Nim128::from(nb));
+                assert_eq!(
+                    Nimber::<usize>::from(na + nb),
+                    Nimber::from(a as usize ^ b as usize)
+                );
+            }
+
+            assert_eq!(
+                Nim8::try_from(Nim64::from(Nim8::from(a))),
+                Ok(Nim8::from(a))
+            );
+        }
+
+        assert_eq!(
+            Nim32::try_from(Nim128::from(u32::MAX as u128)),
+            Ok(Nim32::from(u32::MAX))
+        );
+        assert_eq!(
+            Nim32::try_from(Nim128::from(1 << 32)),
+            Err(NimberError::NotInSubfield)
+        );
+        assert_eq!(
+            Nim8::try_from(Nimber::from(256usize)),
+            Err(NimberError::NotInSubfield)
+        );
+        assert_eq!(
+            Nimber::<usize>::try_from(Nim32::from(7)),
+            Ok(Nimber::from(7usize))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "num-bigint")]
+    fn bigint_convert() {
+        use std::convert::TryFrom;
+
+        let a = Nim64::from(0xdead_beef);
+        let big = BigNim::from(a);
+
+        assert_eq!(big, BigNim::from(BigUint::from(0xdead_beef_u64)));
+        assert_eq!(Nim64::try_from(big.clone()), Ok(a));
+        assert_eq!(Nim32::try_from(big.clone()), Ok(Nim32::from(0xdead_beef)));
+        assert_eq!(Nim16::try_from(big), Err(NimberError::NotInSubfield));
+        assert_eq!(
+            Nim128::try_from(BigNim::from(BigUint::from(1u8) << 128)),
+            Err(NimberError::NotInSubfield)
+        );
+    }
+
+    #[test]
+    fn mixed_width() {
+        for a in u8::MIN..=u8::MAX {
+            let na = Nim8::from(a);
+
+            for &b in &[1u64, 255, 0xdead_beef, u64::MAX] {
+                let nb = Nim64::from(b);
+                let expected = Nim64::from(na) * nb;
+
+                assert_eq!(na * nb, expected);
+                assert_eq!(nb * na, expected);
+                assert_eq!(
+                    nb / na.max(Nim8::from(1)),
+                    nb / Nim64::from(na.max(Nim8::from(1)))
+                );
+                assert_eq!(na / nb, Nim64::from(na) / nb);
+
+                let mut c = nb;
+                c *= na;
+                assert_eq!(c, expected);
+            }
+
+            let nc = Nim32::from(0x0123_4567);
+            assert_eq!(Nim16::from(na) * nc, Nim32::from(na) * nc);
+            assert_eq!(nc * Nim16::from(na), Nim32::from(na) * nc);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "num-bigint")]
+    fn bigint_mixed_width() {
+        let a = Nim64::from(0xdead_beef_0123_4567);
+        let b = BigNim::from(BigUint::from(3u8) << 200);
+
+        assert_eq!(a * &b, BigNim::from(a) * &b);
+        assert_eq!(&b * a, BigNim::from(a) * &b);
+        assert_eq!(&b / a * a, b);
+    }
+
+    #[test]
+    fn split_join() {
+        let c = Nim64::from(1 << 31);
+
+        for &(a, b) in &[
+            (0u64, 0u64),
+            (1, 0xffff_ffff_0000_0000),
+            (0xdead_beef_0123_4567, 0x89ab_cdef_dead_beef),
+            (u64::MAX, u64::MAX),
+        ] {
+            let (na, nb) = (Nim64::from(a), Nim64::from(b));
+            let (ah, al) = na.split_at_level(5);
+            let (bh, bl) = nb.split_at_level(5);
+
+            assert_eq!(Nim64::join_at_level(&ah, &al, 5), na);
+            assert_eq!(
+                na.split(),
+                (Nim32::from(ah.x as u32), Nim32::from(al.x as u32))
+            );
+            assert_eq!(Nim64::join(na.split().0, na.split().1), na);
+
+            let low = al * bl;
+            let high = (ah + al) * (bh + bl) + low;
+            let x = Nim64::from(1 << 32);
+            assert_eq!(na * nb, high * x + ah * bh * c + low);
+            assert_eq!(
+                na * nb,
+                Nim64::join_at_level(&high, &(ah * bh * c + low), 5)
+            );
+
+            // four coordinates over Nim16
+            let (h, l) = na.split();
+            let coords = [h.split(), l.split()];
+            let joined = Nim64::join(
+                Nim32::join(coords[0].0, coords[0].1),
+                Nim32::join(coords[1].0, coords[1].1),
+            );
+            assert_eq!(joined, na);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the level must be below the field of the type")]
+    fn split_whole_field() {
+        let _ = Nim32::from(5).split_at_level(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "the high half must lie in the subfield")]
+    fn join_high_outside_subfield() {
+        let _ = Nim64::join_at_level(&Nim64::from(1 << 32), &Nim64::from(1), 5);
+    }
+
+    #[test]
+    fn minimal_polynomial() {
+        assert_eq!(Nim8::from(0).minimal_polynomial(), vec![false, true]);
+        assert_eq!(Nim8::from(1).minimal_polynomial(), vec![true, true]);
+        assert_eq!(Nim8::from(2).minimal_polynomial(), vec![true, true, true]);
+
+        for a in u8::MIN..=u8::MAX {
+            let na = Nim8::from(a);
+            let poly = na.minimal_polynomial();
+            let conjugates = na.conjugates();
+
+            assert_eq!(poly.len(), na.degree() + 1);
+            assert_eq!(conjugates.len(), na.degree());
+            assert_eq!(na.pow(1 << na.degree()), na);
+
+            for c in &conjugates {
+                let value = poly
+                    .iter()
+                    .rev()
+                    .fold(Nim8::from(0), |acc, &coef| acc * c + Nim8::from(coef as u8));
+
+                assert_eq!(value, Nim8::from(0));
+                assert_eq!(c.minimal_polynomial(), poly);
+            }
+
+            let mut sorted = conjugates.clone();
+            sorted.sort();
+            sorted.dedup();
+            assert_eq!(sorted.len(), conjugates.len());
+        }
+
+        let a = Nim128::from(0x0123_4567_89ab_cdef_0011_2233_4455_6677);
+        let poly = a.minimal_polynomial();
+        assert_eq!(a.degree(), 128);
+        assert_eq!(poly.len(), 129);
+
+        let value = poly.iter().rev().fold(Nim128::from(0), |acc, &coef| {
+            acc * a + Nim128::from(coef as u128)
+        });
+        assert_eq!(value, Nim128::from(0));
+    }
+
+    fn eval(coefs: &[Nim8], x: Nim8) -> Nim8 {
+        coefs.iter().fold(Nim8::from(0), |acc, &c| acc * x + c)
+    }
+
+    fn brute_roots(coefs: &[Nim8]) -> Vec<Nim8> {
+        (u8::MIN..=u8::MAX)
+            .map(Nim8::from)
+            .filter(|&x| eval(coefs, x) == Nim8::from(0))
+            .collect()
+    }
+
+    #[test]
+    fn solve_cubic() {
+        for &a in &[0u8, 1, 7] {
+            for b in (u8::MIN..=u8::MAX).step_by(17) {
+                for c in (u8::MIN..=u8::MAX).step_by(17) {
+                    for d in (u8::MIN..=u8::MAX).step_by(17) {
+                        let coefs: Vec<_> = [a, b, c, d].iter().map(|&x| Nim8::from(x)).collect();
+                        let mut roots =
+                            crate::solve_cubic(&coefs[0], &coefs[1], &coefs[2], &coefs[3]);
+                        roots.sort();
+
+                        if a == 0 && b == 0 && c == 0 {
+                            assert!(roots.is_empty());
+                        } else {
+                            assert_eq!(roots, brute_roots(&coefs));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn solve_quartic() {
+        for &a in &[0u8, 1, 3] {
+            for b in (u8::MIN..=u8::MAX).step_by(37) {
+                for c in (u8::MIN..=u8::MAX).step_by(37) {
+                    for d in (u8::MIN..=u8::MAX).step_by(37) {
+                        for e in (u8::MIN..=u8::MAX).step_by(37) {
+                            let coefs: Vec<_> =
+                                [a, b, c, d, e].iter().map(|&x| Nim8::from(x)).collect();
+                            let mut roots = crate::solve_quartic(
+                                &coefs[0], &coefs[1], &coefs[2], &coefs[3], &coefs[4],
+                            );
+                            roots.sort();
+
+                            if a == 0 && b == 0 && c == 0 && d == 0 {
+                                assert!(roots.is_empty());
+                            } else {
+                                assert_eq!(roots, brute_roots(&coefs));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn solve_cubic_quartic_wide() {
+        let k = Nim64::from(0x0123_4567_89ab_cdef);
+        let rs = [0u64, 1, 2, 0xdead_beef, 1 << 63, u64::MAX].map(Nim64::from);
+
+        for w in rs.windows(4) {
+            let (r0, r1, r2, r3) = (w[0], w[1], w[2], w[3]);
+
+            let s1 = r0 + r1 + r2;
+            let s2 = r0 * r1 + r0 * r2 + r1 * r2;
+            let s3 = r0 * r1 * r2;
+            let mut roots = crate::solve_cubic(&k, &(k * s1), &(k * s2), &(k * s3));
+            roots.sort();
+            let mut expected = vec![r0, r1, r2];
+            expected.sort();
+            assert_eq!(roots, expected);
+
+            let t1 = s1 + r3;
+            let t2 = s2 + s1 * r3;
+            let t3 = s3 + s2 * r3;
+            let t4 = s3 * r3;
+            let mut roots = crate::solve_quartic(&k, &(k * t1), &(k * t2), &(k * t3), &(k * t4));
+            roots.sort();
+            expected.push(r3);
+            expected.sort();
+            assert_eq!(roots, expected);
+        }
+
+        // x^3 + x + 1 splits only in GF(8), which is not a subfield of any nimber field
+        let (zero, one) = (Nim64::from(0), Nim64::from(1));
+        assert!(crate::solve_cubic(&one, &zero, &one, &one).is_empty());
+
+        // (x + r) (x^2 + x + c) has a single root if the trace of c is 1
+        let c = Nim64::from(1 << 63);
+        assert_eq!(c.trace(), one);
+        let r = Nim64::from(0xdead_beef);
+        let roots = crate::solve_cubic(&one, &(one + r), &(c + r), &(c * r));
+        assert_eq!(roots, vec![r]);
+
+        let roots = crate::solve_quartic(&one, &(one + r), &(c + r), &(c * r), &zero);
+        assert_eq!(roots.len(), 2);
+        assert!(roots.contains(&zero) && roots.contains(&r));
+    }
+
+    #[test]
+    fn checked_div() {
+        assert_eq!(
+            Nimber::from(0u8).checked_recip(),
+            Err(NimberError::DivisionByZero)
+        );
+
+        for a in u8::MIN..u8::MAX {
+            let na = Nimber::from(a);
+
+            assert_eq!(
+                na.checked_div(&Nimber::from(0)),
+                Err(NimberError::DivisionByZero)
+            );
+
+            for b in 1u8..u8::MAX {
+                let nb = Nimber::from(b);
+
+                assert_eq!(na.checked_div(&nb), Ok(na / nb));
+            }
+        }
+
+        for a in 1u8..u8::MAX {
+            assert_eq!(Nimber::from(a).checked_recip(), Ok(Nimber::from(a).recip()));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn div_by_zero() {
+        let _ = Nim64::from(5) / Nim64::from(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn recip_of_zero() {
+        let _ = Nim64::from(0).recip();
+    }
+
+    // (a + b)^2 / a, written only against the NimberRepr bound
+    fn generic_arithmetic<T: NimberRepr>(a: &Nimber<T>, b: &Nimber<T>) -> Nimber<T> {
+        let sum = a.clone() + b;
+
+        sum.square() * a.recip() + &sum * &sum / a + a.clone() * b / a
+    }
+
+    #[test]
+    fn repr() {
+        for a in 1u8..=u8::MAX {
+            for b in (0u8..=u8::MAX).step_by(7) {
+                let (na, nb) = (Nim8::from(a), Nim8::from(b));
+
+                assert_eq!(generic_arithmetic(&na, &nb), nb);
+                assert_eq!(
+                    generic_arithmetic(&Nim64::from(na), &Nim64::from(nb)),
+                    Nim64::from(nb)
+                );
+            }
+        }
+
+        let (a, b) = (
+            Nim128::from(u128::MAX),
+            Nim128::from(0xdead_beef_u128 << 64),
+        );
+        assert_eq!(generic_arithmetic(&a, &b), b);
+
+        assert_eq!(u8::MAX_LEVEL, Some(3));
+        assert_eq!(u128::MAX_LEVEL, Some(7));
+        assert_eq!(usize::MAX_LEVEL, Some(usize::BITS.trailing_zeros() as u8));
+    }
+
+    #[test]
+    #[cfg(feature = "num-bigint")]
+    fn bigint_repr() {
+        let a = BigNim::from(BigUint::from(0xdead_beef_u64) << 200);
+        let b = BigNim::from(BigUint::from(12345u32));
+
+        assert_eq!(generic_arithmetic(&a, &b), b);
+        assert_eq!(BigUint::MAX_LEVEL, None);
+    }
+
+    // evaluates the polynomial through the points (xs[i], ys[i]) at x by Lagrange interpolation
+    fn interpolate<F: NimberField>(xs: &[F], ys: &[F], x: &F) -> F {
+        let mut ans = F::field_zero();
+
+        for (i, (xi, yi)) in xs.iter().zip(ys).enumerate() {
+            let mut term = yi.clone();
+
+            for (j, xj) in xs.iter().enumerate() {
+                if i != j {
+                    term *= (x.clone() - xj) / (xi.clone() - xj);
+                }
+            }
+
+            ans += term;
+        }
+
+        ans
+    }
+
+    fn check_field<F: NimberField>(bits: &[F::Bits]) {
+        let xs: Vec<F> = bits.iter().cloned().map(F::from_bits).collect();
+
+        for (x, b) in xs.iter().zip(bits) {
+            assert_eq!(F::from_bits(x.clone().to_bits()), *x);
+            assert_eq!(F::from_bits(b.clone()), *x);
+        }
+
+        // the polynomial 1 + x + x^3
+        let f = |x: &F| F::field_one() + x + x.pow(3);
+        let ys: Vec<F> = xs.iter().map(f).collect();
+
+        for x in &xs {
+            let y = x.square() + F::field_one();
+            assert_eq!(interpolate(&xs, &ys, &y), f(&y));
+            assert_eq!(x.sqrt().square(), *x);
+
+            if *x != F::field_zero() {
+                assert_eq!(x.clone() * x.recip(), F::field_one());
+                assert_eq!(F::field_one().checked_div(x), Ok(x.recip()));
+
+                if let Some(order) = F::ORDER {
+                    assert_eq!(x.pow_u128(order), F::field_one());
+                }
+            } else {
+                assert_eq!(x.checked_recip(), Err(NimberError::DivisionByZero));
+            }
+        }
+
+        assert_eq!(F::field_zero(), F::default());
+        assert_eq!(-F::field_one(), F::field_one());
+        assert_eq!(F::LEVEL.map(|level| 1 << level), F::BITS);
+    }
+
+    fn check_finite_field<F: FiniteNimberField>() {
+        assert_eq!(F::ZERO, F::field_zero());
+        assert_eq!(F::ONE, F::field_one());
+        assert_eq!(F::ONE + F::ONE, F::ZERO);
+    }
+
+    #[test]
+    fn field() {
+        check_field::<Nim8>(&[0, 1, 2, 3, 200]);
+        check_field::<Nim16>(&[0, 1, 7, 300, 65000]);
+        check_field::<Nim32>(&[0, 1, 5, 1 << 20, 0xdead_beef]);
+        check_field::<Nim64>(&[0, 1, 9, 1 << 40, u64::MAX]);
+        check_field::<Nim128>(&[0, 1, 11, 1 << 100, u128::MAX]);
+
+        check_finite_field::<Nim8>();
+        check_finite_field::<Nim64>();
+
+        assert_eq!(Nim8::ORDER, Some(255));
+        assert_eq!(Nim32::LEVEL, Some(5));
+        assert_eq!(Nim128::ORDER, Some(u128::MAX));
+    }
+
+    #[test]
+    #[cfg(feature = "num-bigint")]
+    fn field_big() {
+        check_field::<BigNim>(&[
+            BigUint::from(0u8),
+            BigUint::from(1u8),
+            BigUint::from(0xdead_beef_u64),
+            BigUint::from(1u8) << 200,
+            BigUint::from(u128::MAX) << 100,
+        ]);
+
+        assert_eq!(BigNim::ORDER, None);
+        assert_eq!(BigNim::LEVEL, None);
+        assert_eq!(BigNim::field_one(), BigNim::from(BigUint::from(1u8)));
+    }
+
+    #[test]
+    fn sum_product() {
+        let xs: Vec<Nim16> = (1u16..100).map(|a| Nimber::from(a * 601)).collect();
+
+        let sum = xs.iter().fold(Nim16::from(0), |acc, a| acc + a);
+        let product = xs.iter().fold(Nim16::from(1), |acc, a| acc * a);
+
+        assert_eq!(xs.iter().sum::<Nim16>(), sum);
+        assert_eq!(xs.iter().copied().sum::<Nim16>(), sum);
+        assert_eq!(xs.iter().product::<Nim16>(), product);
+        assert_eq!(xs.iter().copied().product::<Nim16>(), product);
+
+        assert_eq!(std::iter::empty::<Nim8>().sum::<Nim8>(), Nim8::from(0));
+        assert_eq!(std::iter::empty::<Nim8>().product::<Nim8>(), Nim8::from(1));
+    }
+
+    #[test]
+    #[cfg(feature = "num-traits")]
+    fn rem() {
+        for a in 0u8..=u8::MAX {
+            for b in 1u8..=u8::MAX {
+                let (na, nb) = (Nim8::from(a), Nim8::from(b));
+
+                assert_eq!(na % nb, Nim8::from(0));
+                assert_eq!(na / nb * nb + na % nb, na);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "num-traits")]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn rem_by_zero() {
+        let _ = Nim32::from(5) % Nim32::from(0);
+    }
+
+    #[cfg(feature = "num-traits")]
+    fn generic_num<N: num_traits::Num + Clone>(a: N) -> N {
+        // (a + 1)^2 - a^2 - 1 = 2a, which is 0 in characteristic 2
+        let b = a.clone() + N::one();
+
+        b.clone() * b - a.clone() * a.clone() - N::one() + a.clone() / a
+    }
+
+    #[test]
+    #[cfg(feature = "num-traits")]
+    fn num_traits() {
+        use num_traits::{Bounded, CheckedDiv, Inv, Num, One, Pow, Zero};
+
+        assert!(Nim8::zero().is_zero());
+        assert!(Nim8::one().is_one());
+        assert!(!Nim8::from(2).is_one());
+
+        for a in 1u8..=u8::MAX {
+            let na = Nim8::from(a);
+
+            assert_eq!(generic_num(na), Nim8::one());
+            assert_eq!(generic_num(Nim128::from(na)), Nim128::one());
+            assert_eq!(na.inv(), na.recip());
+            assert_eq!((&na).inv(), na.recip());
+            assert_eq!(CheckedDiv::checked_div(&Nim8::one(), &na), Some(na.recip()));
+            assert_eq!(Pow::pow(na, 5u32), na.pow(5));
+            assert_eq!(Pow::pow(&na, 300u128), na.pow_u128(300));
+            assert_eq!(Pow::pow(na, -3i32), na.powi(-3));
+        }
+
+        assert_eq!(CheckedDiv::checked_div(&Nim8::one(), &Nim8::zero()), None);
+
+        assert_eq!(Nim16::from_str_radix("ff", 16), Ok(Nim16::from(255)));
+        assert_eq!(Nim16::from_str_radix("101", 2), Ok(Nim16::from(5)));
+        assert_eq!(Nim8::from_str_radix("256", 10), Err(NimberError::Parse));
+        assert_eq!(Nim8::from_str_radix("x", 10), Err(NimberError::Parse));
+
+        assert_eq!(Nim8::min_value(), Nim8::from(0));
+        assert_eq!(Nim64::max_value(), Nim64::from(u64::MAX));
+    }
+
+    #[test]
+    #[cfg(all(feature = "num-traits", feature = "num-bigint"))]
+    fn bigint_num_traits() {
+        use num_traits::{Num, One, Pow, Zero};
+
+        let a = BigNim::from(BigUint::from(0xdead_beef_u64) << 100);
+
+        assert!(BigNim::zero().is_zero());
+        assert!(BigNim::one().is_one());
+        assert_eq!(generic_num(a.clone()), BigNim::one());
+        assert_eq!(
+            BigNim::from_str_radix("deadbeef", 16),
+            Ok(BigNim::from(BigUint::from(0xdead_beef_u64)))
+        );
+        assert_eq!(Pow::pow(&a, &BigUint::from(3u8)), Nimber::pow(&a, 3));
+        assert_eq!(
+            vec![a.clone(), a.clone()].into_iter().sum::<BigNim>(),
+            BigNim::zero()
+        );
+    }
+
+    #[cfg(feature = "ff")]
+    fn check_ff<F: ff::Field>(rng: &mut impl rand::RngCore) {
+        assert!(bool::from(F::ZERO.is_zero()));
+        assert!(bool::from(F::ZERO.invert().is_none()));
+        assert_eq!(F::ONE.double(), F::ZERO);
+
+        let (is_square, root) = F::sqrt_ratio(&F::ONE, &F::ZERO);
+        assert!(!bool::from(is_square));
+        assert_eq!(root, F::ZERO);
+
+        let (is_square, root) = F::sqrt_ratio(&F::ZERO, &F::ZERO);
+        assert!(bool::from(is_square));
+        assert_eq!(root, F::ZERO);
+
+        for _ in 0..100 {
+            let (a, b) = (F::random(&mut *rng), F::random(&mut *rng));
+
+            assert_eq!(a + a, F::ZERO);
+            assert_eq!(a.square(), a * a);
+            assert_eq!(a.cube(), a * a * a);
+            assert_eq!(a.sqrt().unwrap().square(), a);
+            assert_eq!(a.pow_vartime([5u64]), a.square().square() * a);
+
+            if !bool::from(b.is_zero()) {
+                assert_eq!(b.invert().unwrap() * b, F::ONE);
+
+                let (is_square, root) = F::sqrt_ratio(&a, &b);
+                assert!(bool::from(is_square));
+                assert_eq!(root.square() * b, a);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "ff")]
+    fn ff() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(17);
+
+        check_ff::<Nim8>(&mut rng);
+        check_ff::<Nim16>(&mut rng);
+        check_ff::<Nim32>(&mut rng);
+        check_ff::<Nim64>(&mut rng);
+        check_ff::<Nim128>(&mut rng);
+
+        assert_eq!(
+            ff::Field::invert(&Nim64::from(12345)).unwrap(),
+            Nim64::from(12345).recip()
+        );
+        assert_eq!(
+            ff::Field::sqrt(&Nim32::from(7)).unwrap(),
+            Nim32::from(7).sqrt()
+        );
+    }
+
+    #[test]
+    fn format() {
+        let a = Nim16::from(0x1f);
+
+        assert_eq!(format!("{}", a), "31");
+        assert_eq!(format!("{:#}", a), "*31");
+        assert_eq!(format!("{:>5}", a), "   31");
+        assert_eq!(format!("{:#5}", a), "  *31");
+        assert_eq!(format!("{:#05}", a), "*0031");
+        assert_eq!(format!("{:x}", a), "1f");
+        assert_eq!(format!("{:#X}", a), "0x1F");
+        assert_eq!(format!("{:b}", a), "11111");
+        assert_eq!(format!("{:#010b}", a), "0b00011111");
+        assert_eq!(format!("{:o}", a), "37");
+        assert_eq!(format!("{:?}", a), "Nimber { x: 31 }");
+        assert_eq!(Nim128::from(u128::MAX).to_string(), u128::MAX.to_string());
+    }
+
+    #[test]
+    fn from_str() {
+        for s in &[
+            "31", "0x1f", "0x1F", "0b11111", "*31", "*0x1f", "*0b11111", "031",
+        ] {
+            assert_eq!(s.parse(), Ok(Nim8::from(31)));
+            assert_eq!(s.parse(), Ok(Nim128::from(31)));
+        }
+
+        for s in &[
+            "", "*", "0x", "0b", "**1", "+1", "-1", " 1", "1_0", "0b2", "1f", "0o7", "*-1",
+        ] {
+            assert_eq!(s.parse::<Nim64>(), Err(NimberError::Parse));
+        }
+
+        assert_eq!("255".parse(), Ok(Nim8::from(255)));
+        assert_eq!("256".parse::<Nim8>(), Err(NimberError::Parse));
+        assert_eq!("0x100".parse::<Nim8>(), Err(NimberError::Parse));
+
+        for a in (0u64..u64::MAX).step_by(0x1234_5678_9abc) {
+            let na = Nim64::from(a);
+
+            assert_eq!(na.to_string().parse(), Ok(na));
+            assert_eq!(format!("{:#}", na).parse(), Ok(na));
+            assert_eq!(format!("{:#x}", na).parse(), Ok(na));
+            assert_eq!(format!("{:#b}", na).parse(), Ok(na));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "num-bigint")]
+    fn bigint_format() {
+        let a = BigNim::from(BigUint::from(0xdead_beef_u64) << 100);
+        let s = "4735852080226134139055010849311040733184";
+
+        assert_eq!(a.to_string(), s);
+        assert_eq!(format!("{:#}", a), format!("*{}", s));
+        assert_eq!(format!("{:#x}", a), format!("0xdeadbeef{}", "0".repeat(25)));
+        assert_eq!(s.parse(), Ok(a.clone()));
+        assert_eq!(format!("{:#}", a).parse(), Ok(a.clone()));
+        assert_eq!(format!("{:#x}", a).parse(), Ok(a.clone()));
+        assert_eq!(format!("{:#b}", a).parse(), Ok(a));
+
+        for s in &["", "*", "0x", "1_0", "+1", "0xg"] {
+            assert_eq!(s.parse::<BigNim>(), Err(NimberError::Parse));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Record {
+        plain: Nim64,
+        #[serde(with = "crate::serde_hex")]
+        hex: Nim128,
+        list: Vec<Nim8>,
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let record = Record {
+            plain: Nim64::from(u64::MAX),
+            hex: Nim128::from(u128::MAX - 1),
+            list: vec![Nim8::from(0), Nim8::from(31)],
+        };
+
+        let json = serde_json::to_string(&record).unwrap();
+        assert_eq!(
+            json,
+            r#"{"plain":18446744073709551615,"hex":"0xfffffffffffffffffffffffffffffffe","list":[0,31]}"#
+        );
+        assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), record);
+
+        let bytes = bincode::serialize(&record).unwrap();
+        assert_eq!(bytes.len(), 8 + 16 + 8 + 2);
+        assert_eq!(bincode::deserialize::<Record>(&bytes).unwrap(), record);
+
+        for s in &[r#""*31""#, r#""31""#, r#""0b11111""#, r#""0x1F""#] {
+            let mut de = serde_json::Deserializer::from_str(s);
+            assert_eq!(
+                crate::serde_hex::deserialize(&mut de).unwrap(),
+                Nim16::from(31)
+            );
+        }
+
+        let mut de = serde_json::Deserializer::from_str(r#""0x100""#);
+        assert!(crate::serde_hex::deserialize::<u8, _>(&mut de).is_err());
+
+        let mut de = serde_json::Deserializer::from_str("31");
+        assert!(crate::serde_hex::deserialize::<u8, _>(&mut de).is_err());
+
+        assert_eq!(
+            serde_json::from_str::<Nim8>("255").unwrap(),
+            Nim8::from(255)
+        );
+        assert!(serde_json::from_str::<Nim8>("256").is_err());
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "num-bigint"))]
+    fn bigint_serde() {
+        let a = BigNim::from(BigUint::from(0xdead_beef_u64) << 100);
+
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(serde_json::from_str::<BigNim>(&json).unwrap(), a);
+
+        let mut json = Vec::new();
+        crate::serde_hex::serialize(&a, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        assert_eq!(
+            json,
+            format!(r#""0xdeadbeef{}""#, "0".repeat(25)).into_bytes()
+        );
+
+        let mut de = serde_json::Deserializer::from_slice(&json);
+        assert_eq!(crate::serde_hex::deserialize(&mut de).unwrap(), a);
+
+        let bytes = bincode::serialize(&a).unwrap();
+        assert_eq!(bincode::deserialize::<BigNim>(&bytes).unwrap(), a);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn random() {
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(20);
+
+        let mut seen = [false; 256];
+        for _ in 0..10000 {
+            seen[rng.gen::<Nim8>().unwrap() as usize] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        let mut seen = [false; 256];
+        for _ in 0..10000 {
+            let a: Nim8 = rng.sample(UniformNonzero);
+            seen[a.unwrap() as usize] = true;
+        }
+        assert!(!seen[0] && seen[1..].iter().all(|&s| s));
+
+        for level in 0..=2 {
+            let size = 1 << (1 << level);
+
+            let mut seen = vec![false; size];
+            for _ in 0..1000 {
+                let a: Nim64 = rng.sample(UniformSubfield::new(level));
+                seen[a.unwrap() as usize] = true;
+            }
+            assert!(seen.iter().all(|&s| s));
+
+            let mut seen = vec![false; size];
+            for _ in 0..1000 {
+                let a: Nim128 = rng.sample(UniformSubfield::nonzero(level));
+                seen[a.unwrap() as usize] = true;
+            }
+            assert!(!seen[0] && seen[1..].iter().all(|&s| s));
+        }
+
+        for _ in 0..1000 {
+            let a: Nim64 = rng.sample(UniformSubfield::nonzero(5));
+            assert!(a != Nim64::from(0) && a.unwrap() <= u32::MAX as u64);
+
+            let a: Nim64 = rng.sample(UniformSubfield::new(6));
+            let b: Nim64 = rng.sample(UniformNonzero);
+            assert_eq!(a * b / b, a);
+        }
+
+        assert!(
+            (0..100).any(|_| rng.sample::<Nim128, _>(UniformSubfield::new(7)).unwrap() >> 127 == 1)
+        );
+        assert!(rng.sample::<Nim8, _>(UniformSubfield::nonzero(3)) != Nim8::from(0));
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    #[should_panic(expected = "the level exceeds the field of the type")]
+    fn random_level_too_high() {
+        let _: Nim16 = rand::Rng::sample(&mut rand::thread_rng(), UniformSubfield::new(5));
+    }
+
+    #[test]
+    #[cfg(all(feature = "rand", feature = "num-bigint"))]
+    fn bigint_random() {
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(20);
+
+        for level in 0..=9 {
+            let mut top = false;
+
+            for _ in 0..100 {
+                let a: BigNim = rng.sample(UniformSubfield::new(level));
+                assert!(a.unwrap().bits() <= 1 << level);
+
+                let a: BigNim = rng.sample(UniformSubfield::nonzero(level));
+                let bits = a.clone().unwrap().bits();
+                assert!(bits >= 1 && bits <= 1 << level);
+                top |= bits == 1 << level;
+            }
+
+            assert!(top);
+        }
+    }
+
+    // product of the halves over the subfield at level lvl, checked by the given multiplication
+    fn split_mul(a: u64, b: u64, lvl: u32, mul: impl Fn(u64, u64) -> u64) -> u64 {
+        let half = 1 << lvl;
+        let mask = (1 << half) - 1;
+        let (ah, al, bh, bl) = (a >> half, a & mask, b >> half, b & mask);
+
+        let low = mul(al, bl);
+        let high = mul(ah ^ al, bh ^ bl) ^ low;
+        let low = mul(mul(ah, bh), 1 << (half - 1)) ^ low;
+
+        (high << half) | low
+    }
+
+    #[test]
+    fn tables() {
+        let mul4 = |a: u64, b: u64| MUL_TABLE[a as usize][b as usize] as u64;
+        let mul8 = |a: u64, b: u64| (Nim8::from(a as u8) * Nim8::from(b as u8)).unwrap() as u64;
+        let mul16 =
+            |a: u64, b: u64| (Nim16::from(a as u16) * Nim16::from(b as u16)).unwrap() as u64;
+
+        for a in 0..=u8::MAX as u64 {
+            for b in 0..=u8::MAX as u64 {
+                assert_eq!(mul8(a, b), split_mul(a, b, 2, mul4));
+            }
+        }
+
+        for a in (0..=u16::MAX as u64).step_by(97) {
+            for b in (0..=u16::MAX as u64).step_by(89) {
+                assert_eq!(mul16(a, b), split_mul(a, b, 3, mul8));
+            }
+        }
+
+        for a in (0..=u32::MAX as u64).step_by(0x0123_4567) {
+            for b in (0..=u32::MAX as u64).step_by(0x0765_4321) {
+                let ab = (Nim32::from(a as u32) * Nim32::from(b as u32)).unwrap() as u64;
+                assert_eq!(ab, split_mul(a, b, 4, mul16));
+            }
+        }
+
+        for a in 0..=u16::MAX {
+            let na = Nim16::from(a);
+
+            assert_eq!(na.square(), na * na);
+            assert_eq!(na.sqrt().square(), na);
+            assert_eq!(na.pow(3), na * na * na);
+
+            if a != 0 {
+                assert_eq!(na.recip() * na, Nim16::from(1));
+                assert_eq!(na.pow_u128(65534), na.recip());
+            }
+        }
+    }
+
+    const GENERATOR: Nim64 = Nim64::new(0x1_0000_0006);
+    const POWERS: [Nim64; 8] = {
+        let mut powers = [Nim64::ONE; 8];
+        let mut i = 1;
+
+        while i < powers.len() {
+            powers[i] = powers[i - 1].const_mul(GENERATOR);
+            i += 1;
+        }
+
+        powers
+    };
+    static INVERSE: Nim128 = Nim128::new(u128::MAX).const_recip();
+
+    #[test]
+    fn const_arithmetic() {
+        use crate::multiplication::{
+            nimber_inverse, nimber_mul_nimber, nimber_sqrt, nimber_square,
+        };
+
+        for (i, p) in POWERS.iter().enumerate() {
+            assert_eq!(*p, Nim64::primitive_element().pow(i as u64));
+        }
+        assert_eq!(INVERSE, Nim128::from(u128::MAX).recip());
+        assert_eq!(Nim8::ZERO.get(), 0);
+        assert_eq!(Nim32::ONE, Nim32::from(1));
+        assert_eq!(Nim16::new(5).const_add(Nim16::new(3)), Nim16::from(6));
+
+        for a in 0..=u8::MAX {
+            let na = Nim8::new(a);
+
+            assert_eq!(na.const_square(), na.square());
+            assert_eq!(na.const_sqrt(), na.sqrt());
+
+            if a != 0 {
+                assert_eq!(na.const_recip(), na.recip());
+            }
+
+            for b in 0..=u8::MAX {
+                assert_eq!(
+                    na.const_mul(Nim8::new(b)),
+                    nimber_mul_nimber::<u8>(&na, &Nim8::new(b), 3)
+                );
+            }
+        }
+
+        let mut x = 0x0123_4567_89ab_cdef_u64;
+        for _ in 0..1000 {
+            x = x.wrapping_mul(0x5851_f42d_4c95_7f2d).wrapping_add(1);
+            let (a, b) = (x, x.rotate_left(17) >> (x % 64));
+
+            for (na, nb) in [
+                (Nim64::new(a), Nim64::new(b)),
+                (Nim64::new(a >> 40), Nim64::new(b >> 40)),
+            ] {
+                assert_eq!(na.const_mul(nb), nimber_mul_nimber::<u64>(&na, &nb, 6));
+                assert_eq!(na.const_square(), nimber_square::<u64>(&na, 6));
+                assert_eq!(na.const_sqrt(), nimber_sqrt::<u64>(&na, 6));
+
+                if na != Nim64::ZERO {
+                    assert_eq!(na.const_recip(), nimber_inverse::<u64>(&na, 6));
+                }
+            }
+
+            let (na, nb) = (
+                Nim128::new((a as u128) << 64 | b as u128),
+                Nim128::new(b as u128),
+            );
+            assert_eq!(na.const_mul(nb), nimber_mul_nimber::<u128>(&na, &nb, 7));
+            assert_eq!(na.const_recip(), nimber_inverse::<u128>(&na, 7));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn const_recip_of_zero() {
+        let _ = Nim32::ZERO.const_recip();
+    }
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    #[test]
+    fn clmul() {
+        use crate::clmul::{detected, mul_u128, mul_u64};
+
+        let mut values = vec![0, 1, 2, 3, u64::MAX, 1 << 31, 1 << 32, 1 << 63];
+        let mut x = 0xfedc_ba98_7654_3210_u64;
+        for _ in 0..300 {
+            x = x.wrapping_mul(0x5851_f42d_4c95_7f2d).wrapping_add(1);
+            values.push(x >> (x % 64));
+        }
+
+        for &a in &values {
+            for &b in values.iter().step_by(7) {
+                let (na, nb) = (Nim64::new(a), Nim64::new(b));
+                let product = na.const_mul(nb);
+
+                assert_eq!(na * nb, product);
+                if detected() {
+                    assert_eq!(unsafe { mul_u64(a, b) }, product.get());
+                }
+
+                let (na, nb) = (
+                    Nim128::new(((a as u128) << 64) | b as u128),
+                    Nim128::new(((b as u128) << 64) | a.rotate_left(13) as u128),
+                );
+                let product = na.const_mul(nb);
+
+                assert_eq!(na * nb, product);
+                if detected() {
+                    assert_eq!(unsafe { mul_u128(na.get(), nb.get()) }, product.get());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn slice() {
+        let src: Vec<u8> = (0..300u32).map(|i| (i * 167 + 13) as u8).collect();
+
+        for scalar in 0..=u8::MAX {
+            let scalar = Nim8::new(scalar);
+
+            for len in [0, 1, 15, 16, 17, 31, 32, 33, 64, 100, 299] {
+                let src = &src[1..=len];
+                let init: Vec<u8> = src.iter().map(|x| x.rotate_left(3)).collect();
+
+                let mut dst = init.clone();
+                mul_slice(scalar, src, &mut dst);
+                for i in 0..len {
+                    assert_eq!(dst[i], (scalar * Nim8::new(src[i])).get());
+                }
+
+                let mut dst = init.clone();
+                mul_add_slice(scalar, src, &mut dst);
+                for i in 0..len {
+                    assert_eq!(dst[i], init[i] ^ (scalar * Nim8::new(src[i])).get());
+                }
+
+                #[cfg(target_arch = "x86_64")]
+                {
+                    use crate::slice::{x86, Nibbles};
+
+                    let nibbles = Nibbles::new(scalar);
+                    let mut expected = init.clone();
+                    mul_add_slice(scalar, src, &mut expected);
+
+                    if is_x86_feature_detected!("ssse3") {
+                        let mut dst = init.clone();
+                        let done = unsafe { x86::mul_ssse3::<true>(&nibbles, src, &mut dst) };
+                        assert_eq!(done, len / 16 * 16);
+                        assert_eq!(dst[..done], expected[..done]);
+                    }
+                    if is_x86_feature_detected!("avx2") {
+                        let mut dst = init.clone();
+                        let done = unsafe { x86::mul_avx2::<true>(&nibbles, src, &mut dst) };
+                        assert_eq!(done, len / 32 * 32);
+                        assert_eq!(dst[..done], expected[..done]);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the slices have different lengths")]
+    fn slice_lengths() {
+        mul_slice(Nim8::new(3), &[1, 2, 3], &mut [0; 2]);
+    }
+
+    #[test]
+    fn multiplier() {
+        let mut x = 0x0123_4567_89ab_cdef_u64;
+        let values: Vec<u128> = (0..40)
+            .map(|_| {
+                x = x.wrapping_mul(0x5851_f42d_4c95_7f2d).wrapping_add(1);
+                ((x as u128) << 64 | x.rotate_left(29) as u128) >> (x % 128)
+            })
+            .chain([0, 1, 2, 3, u128::MAX])
+            .collect();
+
+        macro_rules! check {
+            ($($t:ty),*) => {
+                $(
+                    let values: Vec<$t> = values.iter().map(|&v| v as $t).collect();
+
+                    for &a in &values {
+                        let na = Nimber::<$t>::new(a);
+                        let m = Multiplier::from(na);
+
+                        assert_eq!(m.scalar(), na);
+                        assert_eq!(format!("{:?}", m), format!("Multiplier({:?})", na));
+
+                        for &b in &values {
+                            assert_eq!(m.apply(Nimber::<$t>::new(b)), na * Nimber::<$t>::new(b));
+                        }
+
+                        for len in [0, 1, 16, 33, values.len()] {
+                            let src = &values[..len];
+                            let init: Vec<$t> = src.iter().map(|x| x.rotate_left(5)).collect();
+
+                            let mut dst = init.clone();
+                            m.apply_slice(src, &mut dst);
+                            for i in 0..len {
+                                assert_eq!(dst[i], m.apply(Nimber::<$t>::new(src[i])).get());
+                            }
+
+                            let mut dst = init.clone();
+                            m.apply_add_slice(src, &mut dst);
+                            for i in 0..len {
+                                assert_eq!(dst[i], init[i] ^ m.apply(Nimber::<$t>::new(src[i])).get());
+                            }
+                        }
+                    }
+                )*
+            };
+        }
+
+        check!(u8, u16, u32, u64, u128, usize);
+    }
+
+    #[test]
+    #[should_panic(expected = "the slices have different lengths")]
+    fn multiplier_lengths() {
+        Multiplier::new(Nim64::new(3)).apply_add_slice(&[1, 2, 3], &mut [0; 4]);
+    }
 }
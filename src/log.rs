@@ -0,0 +1,176 @@
+// Discrete logarithm of nimbers
+// Pohlig-Hellman reduction over the prime factors of the group order
+// (the factors of the Fermat numbers), baby-step giant-step for small factors
+// and Pollard's rho with an r-adding walk for large ones
+
+use super::Nimber;
+use crate::multiplication::{level, Smallest};
+use crate::order::{group_order_factors, inv_mod};
+use crate::repr::NimberRepr;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+// the largest prime order solved by baby-step giant-step
+const BSGS_LIMIT: u128 = 1 << 16;
+
+// number of precomputed multipliers of the r-adding walk
+const WALK_SIZE: usize = 16;
+
+// all the arithmetic below is modulo a prime factor of the group order, which is less than 2^64
+
+#[inline]
+fn mul_mod(a: u128, b: u128, p: u128) -> u128 {
+    a * b % p
+}
+
+#[inline]
+fn sub_mod(a: u128, b: u128, p: u128) -> u128 {
+    (a + p - b) % p
+}
+
+// splitmix64, used to pick the walk multipliers and the starting points
+fn next_random(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+    let mut z = *state;
+
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+    z ^ (z >> 31)
+}
+
+impl<T: NimberRepr> Nimber<T> {
+    // finds x < p such that g^x = h, where g has prime order p <= BSGS_LIMIT
+    fn baby_step_giant_step(g: &Nimber<T>, h: &Nimber<T>, p: u128) -> Option<u128> {
+        let m = (1..).find(|m| m * m >= p).unwrap();
+        let mut baby_steps = HashMap::new();
+        let mut power = Nimber::from(T::from(1 as Smallest));
+
+        for j in 0..m {
+            baby_steps.entry(power.clone()).or_insert(j);
+            power = &power * g;
+        }
+
+        // g^(-m)
+        let giant_step = g.pow_u128(p - m % p);
+        let mut gamma = h.clone();
+
+        for i in 0..m {
+            if let Some(j) = baby_steps.get(&gamma) {
+                return Some((i * m + j) % p);
+            }
+
+            gamma = &gamma * &giant_step;
+        }
+
+        None
+    }
+
+    // finds x < p such that g^x = h, where g has prime order p and h lies in the group of g
+    pub(crate) fn pollard_rho(g: &Nimber<T>, h: &Nimber<T>, p: u128) -> u128 {
+        let partition = |x: &Nimber<T>| {
+            let mut hasher = DefaultHasher::new();
+
+            x.hash(&mut hasher);
+
+            hasher.finish() as usize % WALK_SIZE
+        };
+        let mut seed = 0;
+
+        loop {
+            let mut random_pair = || {
+                let a = next_random(&mut seed) as u128 % p;
+                let b = next_random(&mut seed) as u128 % p;
+
+                (a, b, g.pow_u128(a) * h.pow_u128(b))
+            };
+
+            let walk: Vec<_> = (0..WALK_SIZE).map(|_| random_pair()).collect();
+            let (mut a, mut b, mut x) = random_pair();
+
+            // Brent's cycle detection
+            let (mut saved_a, mut saved_b, mut saved_x) = (a, b, x.clone());
+            let mut power = 1u64;
+            let mut length = 0u64;
+
+            loop {
+                if power == length {
+                    saved_a = a;
+                    saved_b = b;
+                    saved_x = x.clone();
+                    power *= 2;
+                    length = 0;
+                }
+
+                let (da, db, m) = &walk[partition(&x)];
+
+                x = &x * m;
+                a = (a + da) % p;
+                b = (b + db) % p;
+                length += 1;
+
+                if x == saved_x {
+                    break;
+                }
+            }
+
+            // g^a h^b = g^saved_a h^saved_b
+            if b != saved_b {
+                let log = mul_mod(
+                    sub_mod(saved_a, a, p),
+                    inv_mod(sub_mod(b, saved_b, p), p),
+                    p,
+                );
+
+                if g.pow_u128(log) == *h {
+                    return log;
+                }
+            }
+        }
+    }
+
+    /// Returns the discrete logarithm of a nimber to the given base,
+    /// the smallest `n` such that `base.pow_u128(n)` equals `self`.
+    ///
+    /// Returns `None` if there is no such `n`, that is,
+    /// `self` does not lie in the multiplicative group generated by `base`.
+    /// Also returns `None` for nimbers which do not fit in 128 bits.
+    ///
+    /// Uses the Pohlig–Hellman algorithm over the factors of the Fermat numbers,
+    /// baby-step giant-step for small prime factors and Pollard's rho for large ones.
+    /// The running time is dominated by the square root of the largest prime factor
+    /// of the order of `base`, which is 67280421310721 for most generators of `Nim128`.
+    pub fn discrete_log(&self, base: &Nimber<T>) -> Option<u128> {
+        let one = Nimber::from(T::from(1 as Smallest));
+        let order = base.multiplicative_order()?;
+
+        if *self == Nimber::from(T::from(0 as Smallest)) || self.pow_u128(order) != one {
+            return None;
+        }
+
+        let lvl = level::<T>(base);
+        let mut log = 0;
+        let mut modulus = 1;
+
+        for p in group_order_factors(lvl).filter(|p| order % p == 0) {
+            let g = base.pow_u128(order / p);
+            let h = self.pow_u128(order / p);
+
+            let residue = if p <= BSGS_LIMIT {
+                Self::baby_step_giant_step(&g, &h, p)?
+            } else {
+                Self::pollard_rho(&g, &h, p)
+            };
+
+            // Chinese remainder theorem
+            let k = mul_mod(sub_mod(residue, log % p, p), inv_mod(modulus % p, p), p);
+
+            log += modulus * k;
+            modulus *= p;
+        }
+
+        Some(log)
+    }
+}
@@ -0,0 +1,136 @@
+// Mixed-width arithmetic
+// The narrower operand is embedded into the field of the wider one,
+// which is a field homomorphism, so the result is the same as with an explicit conversion
+
+use super::Nimber;
+use std::ops::{Div, DivAssign, Mul, MulAssign};
+
+#[cfg(feature = "num-bigint")]
+use num_bigint::BigUint;
+
+macro_rules! nimber_mixed_binop {
+    (impl $imp:ident, $method:ident, $imp_assign:ident, $method_assign:ident; $($n:ty => $($w:ty),*);*) => {
+        $($(
+            impl $imp<Nimber<$w>> for Nimber<$n> {
+                type Output = Nimber<$w>;
+
+                #[inline]
+                fn $method(self, rhs: Nimber<$w>) -> Self::Output {
+                    $imp::$method(&Nimber::<$w>::from(self), &rhs)
+                }
+            }
+
+            impl<'a> $imp<Nimber<$w>> for &'a Nimber<$n> {
+                type Output = Nimber<$w>;
+
+                #[inline]
+                fn $method(self, rhs: Nimber<$w>) -> Self::Output {
+                    $imp::$method(&Nimber::<$w>::from(*self), &rhs)
+                }
+            }
+
+            impl<'b> $imp<&'b Nimber<$w>> for Nimber<$n> {
+                type Output = Nimber<$w>;
+
+                #[inline]
+                fn $method(self, rhs: &'b Nimber<$w>) -> Self::Output {
+                    $imp::$method(&Nimber::<$w>::from(self), rhs)
+                }
+            }
+
+            impl<'a, 'b> $imp<&'b Nimber<$w>> for &'a Nimber<$n> {
+                type Output = Nimber<$w>;
+
+                #[inline]
+                fn $method(self, rhs: &'b Nimber<$w>) -> Self::Output {
+                    $imp::$method(&Nimber::<$w>::from(*self), rhs)
+                }
+            }
+
+            impl $imp<Nimber<$n>> for Nimber<$w> {
+                type Output = Nimber<$w>;
+
+                #[inline]
+                fn $method(self, rhs: Nimber<$n>) -> Self::Output {
+                    $imp::$method(&self, &Nimber::<$w>::from(rhs))
+                }
+            }
+
+            impl<'a> $imp<Nimber<$n>> for &'a Nimber<$w> {
+                type Output = Nimber<$w>;
+
+                #[inline]
+                fn $method(self, rhs: Nimber<$n>) -> Self::Output {
+                    $imp::$method(self, &Nimber::<$w>::from(rhs))
+                }
+            }
+
+            impl<'b> $imp<&'b Nimber<$n>> for Nimber<$w> {
+                type Output = Nimber<$w>;
+
+                #[inline]
+                fn $method(self, rhs: &'b Nimber<$n>) -> Self::Output {
+                    $imp::$method(&self, &Nimber::<$w>::from(*rhs))
+                }
+            }
+
+            impl<'a, 'b> $imp<&'b Nimber<$n>> for &'a Nimber<$w> {
+                type Output = Nimber<$w>;
+
+                #[inline]
+                fn $method(self, rhs: &'b Nimber<$n>) -> Self::Output {
+                    $imp::$method(self, &Nimber::<$w>::from(*rhs))
+                }
+            }
+
+            impl $imp_assign<Nimber<$n>> for Nimber<$w> {
+                #[inline]
+                fn $method_assign(&mut self, rhs: Nimber<$n>) {
+                    *self = $imp::$method(&*self, &Nimber::<$w>::from(rhs));
+                }
+            }
+
+            impl<'b> $imp_assign<&'b Nimber<$n>> for Nimber<$w> {
+                #[inline]
+                fn $method_assign(&mut self, rhs: &'b Nimber<$n>) {
+                    *self = $imp::$method(&*self, &Nimber::<$w>::from(*rhs));
+                }
+            }
+        )*)*
+    };
+}
+
+// the same pairs as the embeddings in convert.rs
+nimber_mixed_binop!(impl Mul, mul, MulAssign, mul_assign;
+    u8 => u16, u32, u64, u128, usize;
+    u16 => u32, u64, u128, usize;
+    u32 => u64, u128;
+    u64 => u128
+);
+
+nimber_mixed_binop!(impl Div, div, DivAssign, div_assign;
+    u8 => u16, u32, u64, u128, usize;
+    u16 => u32, u64, u128, usize;
+    u32 => u64, u128;
+    u64 => u128
+);
+
+#[cfg(feature = "num-bigint")]
+nimber_mixed_binop!(impl Mul, mul, MulAssign, mul_assign;
+    u8 => BigUint;
+    u16 => BigUint;
+    u32 => BigUint;
+    u64 => BigUint;
+    u128 => BigUint;
+    usize => BigUint
+);
+
+#[cfg(feature = "num-bigint")]
+nimber_mixed_binop!(impl Div, div, DivAssign, div_assign;
+    u8 => BigUint;
+    u16 => BigUint;
+    u32 => BigUint;
+    u64 => BigUint;
+    u128 => BigUint;
+    usize => BigUint
+);
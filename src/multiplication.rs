@@ -3,58 +3,46 @@
 // Some ideas were taken from the C++ implementation by David Eppstein
 // (https://www.ics.uci.edu/~eppstein/numth/)
 
-use super::Nimber;
-use std::ops::{
-    AddAssign, BitAnd, BitOr, BitOrAssign, Div, DivAssign, Mul, MulAssign, Shl, Shr, Sub,
+use super::{Nimber, NimberError};
+use crate::repr::NimberRepr;
+#[cfg(feature = "tables")]
+use crate::table::{
+    table_inverse, table_mul, table_mul_fermat, table_sqrt, table_square, TABLE_LEVEL,
 };
+use std::iter::Product;
+use std::ops::{Div, DivAssign, Mul, MulAssign};
 
-type Smallest = u8;
-type Shift = usize;
-type Level = u8;
+pub(crate) type Smallest = u8;
+pub(crate) type Shift = usize;
+pub(crate) type Level = u8;
 
 // assuming that 1 << 1 << lvl always fits in T
 #[inline]
-fn high_part<'a, T>(a: &'a Nimber<T>, lvl: Level) -> Nimber<T>
-where
-    &'a Nimber<T>: Shr<Shift, Output = Nimber<T>>,
-{
-    a >> ((1 as Shift) << lvl)
+pub(crate) fn high_part<T: NimberRepr>(a: &Nimber<T>, lvl: Level) -> Nimber<T> {
+    Nimber::from(a.x.shr_ref((1 as Shift) << lvl))
 }
 
 // assuming that 1 << 1 << lvl always fits in T
 #[inline]
-fn low_part<'a, T: From<Smallest>>(a: &'a Nimber<T>, lvl: Level) -> Nimber<T>
-where
-    T: Shl<Shift, Output = T> + Sub<Output = T>,
-    Nimber<T>: BitAnd<&'a Nimber<T>, Output = Nimber<T>>,
-{
+pub(crate) fn low_part<T: NimberRepr>(a: &Nimber<T>, lvl: Level) -> Nimber<T> {
     Nimber::from((T::from(1 as Smallest) << ((1 as Shift) << lvl)) - T::from(1 as Smallest)) & a
 }
 
 // assuming that 1 << 1 << lvl always fits in T, even if a = 0
 #[inline]
-fn combine<'a, 'b, T>(high: &'a Nimber<T>, low: &'b Nimber<T>, lvl: Level) -> Nimber<T>
-    where
-        &'a Nimber<T>: Shl<Shift, Output=Nimber<T>>,
-        Nimber<T>: BitOr<&'b Nimber<T>, Output=Nimber<T>>,
-{
-    (high << ((1 as Shift) << lvl)) | low
+pub(crate) fn combine<T: NimberRepr>(high: &Nimber<T>, low: &Nimber<T>, lvl: Level) -> Nimber<T> {
+    Nimber::from(high.x.shl_ref((1 as Shift) << lvl)) | low
 }
 
 // finds smallest level at which high_part is 0
 // implementation differs from the naive (with computing high_part in a loop),
 // because the naive version overflows at shl
-fn level<'a, T: From<Smallest>>(a: &'a Nimber<T>) -> Level
-where
-    &'a Nimber<T>: for<'y> BitAnd<&'y Nimber<T>, Output = Nimber<T>>,
-    for<'x> &'x Nimber<T>: Shl<Shift, Output = Nimber<T>>,
-    Nimber<T>: BitOrAssign<Nimber<T>> + PartialEq,
-{
+pub(crate) fn level<T: NimberRepr>(a: &Nimber<T>) -> Level {
     let mut lvl = 0;
-    let mut low_mask = Nimber::from(T::from(1 as Smallest));
+    let mut low_mask = T::from(1 as Smallest);
 
-    while a & &low_mask != *a {
-        low_mask |= &low_mask << ((1 as Shift) << lvl);
+    while a.x.bitand_ref(&low_mask) != a.x {
+        low_mask |= low_mask.shl_ref((1 as Shift) << lvl);
         lvl += 1;
     }
 
@@ -62,23 +50,12 @@ where
 }
 
 // multiply by 1 << ((1 << lvl) - 1)
-fn nimber_mul_fermat<'a, T: From<Smallest>>(a: &'a Nimber<T>, lvl: Level) -> Nimber<T>
-where
-    // high_part
-    for<'x> &'x Nimber<T>: Shr<Shift, Output = Nimber<T>>,
-
-    // low_part
-    T: Shl<Shift, Output = T> + Sub<Output = T>,
-    Nimber<T>: for<'y> BitAnd<&'y Nimber<T>, Output = Nimber<T>>,
-
-    // combine
-    for<'x> &'x Nimber<T>: Shl<Shift, Output = Nimber<T>>,
-    Nimber<T>: for<'y> BitOr<&'y Nimber<T>, Output = Nimber<T>>,
-
-    // impl
-    Nimber<T>: for<'y> AddAssign<&'y Nimber<T>>,
-    Nimber<T>: Clone,
-{
+pub(crate) fn nimber_mul_fermat<T: NimberRepr>(a: &Nimber<T>, lvl: Level) -> Nimber<T> {
+    #[cfg(feature = "tables")]
+    if lvl <= TABLE_LEVEL {
+        return table_mul_fermat::<T>(a, lvl);
+    }
+
     if lvl == 0 {
         return a.clone();
     }
@@ -98,32 +75,18 @@ where
     combine::<T>(&ansh, &ansl, lvl)
 }
 
-fn nimber_mul_nimber<'a, 'b, T: From<Smallest>>(
-    a: &'a Nimber<T>,
-    b: &'b Nimber<T>,
+pub(crate) fn nimber_mul_nimber<T: NimberRepr>(
+    a: &Nimber<T>,
+    b: &Nimber<T>,
     lvl: Level,
-) -> Nimber<T>
-where
-    // high_part
-    for<'x> &'x Nimber<T>: Shr<Shift, Output = Nimber<T>>,
-
-    // low_part
-    T: Shl<Shift, Output = T> + Sub<Output = T>,
-    Nimber<T>: for<'y> BitAnd<&'y Nimber<T>, Output = Nimber<T>>,
-
-    // combine
-    for<'x> &'x Nimber<T>: Shl<Shift, Output = Nimber<T>>,
-    Nimber<T>: for<'y> BitOr<&'y Nimber<T>, Output = Nimber<T>>,
-
-    // nimber_mul_fermat
-    Nimber<T>: for<'y> AddAssign<&'y Nimber<T>>,
-    Nimber<T>: Clone,
-
-    // impl
-    for<'x, 'y> &'x Nimber<T>: BitAnd<&'y Nimber<T>, Output = Nimber<T>>,
-{
+) -> Nimber<T> {
+    #[cfg(feature = "tables")]
+    if lvl <= TABLE_LEVEL {
+        return table_mul::<T>(a, b, lvl);
+    }
+
     if lvl == 0 {
-        return a & b;
+        return Nimber::from(a.x.bitand_ref(&b.x));
     }
 
     let lvl = lvl - 1;
@@ -151,59 +114,32 @@ where
 /// Multiplication of nimbers
 ///
 /// The complexity is *O*(*n*^(log_2 3) \* log *n*)
-impl<'a, 'b, T: PartialEq + From<Smallest>> Mul<&'b Nimber<T>> for &'a Nimber<T>
-where
-    for<'x> &'x Nimber<T>: Shr<Shift, Output = Nimber<T>>,
-    T: Shl<Shift, Output = T> + Sub<Output = T>,
-    Nimber<T>: for<'y> BitAnd<&'y Nimber<T>, Output = Nimber<T>>,
-    for<'x> &'x Nimber<T>: Shl<Shift, Output = Nimber<T>>,
-    Nimber<T>: for<'y> BitOr<&'y Nimber<T>, Output = Nimber<T>>,
-    Nimber<T>: for<'y> AddAssign<&'y Nimber<T>>,
-    Nimber<T>: Clone,
-    for<'x, 'y> &'x Nimber<T>: BitAnd<&'y Nimber<T>, Output = Nimber<T>>,
-    for<'x> &'x Nimber<T>: Shl<Shift, Output = Nimber<T>>,
-    Nimber<T>: BitOrAssign<Nimber<T>> + PartialEq,
-    &'a Nimber<T>: BitOr<&'b Nimber<T>, Output = Nimber<T>>,
-{
+impl<T: NimberRepr> Mul<&Nimber<T>> for &Nimber<T> {
     type Output = Nimber<T>;
 
     #[inline]
-    fn mul(self, rhs: &'b Nimber<T>) -> Self::Output {
-        nimber_mul_nimber::<T>(self, rhs, level::<T>(&(self | rhs)))
+    fn mul(self, rhs: &Nimber<T>) -> Self::Output {
+        T::nimber_mul(self, rhs)
     }
 }
 
 nimber_ref_binop!(impl Mul, mul);
 nimber_ref_binop_assign!(impl MulAssign, mul_assign use Mul, mul);
 
-fn nimber_square<T: From<Smallest>>(a: &Nimber<T>, lvl: Level) -> Nimber<T>
-where
-    // high_part
-    for<'x> &'x Nimber<T>: Shr<Shift, Output = Nimber<T>>,
-
-    // low_part
-    T: Shl<Shift, Output = T> + Sub<Output = T>,
-    Nimber<T>: for<'y> BitAnd<&'y Nimber<T>, Output = Nimber<T>>,
-
-    // combine
-    for<'x> &'x Nimber<T>: Shl<Shift, Output = Nimber<T>>,
-    Nimber<T>: for<'y> BitOr<&'y Nimber<T>, Output = Nimber<T>>,
-
-    // nimber_mul_fermat
-    Nimber<T>: for<'y> AddAssign<&'y Nimber<T>>,
-    Nimber<T>: Clone,
+pub(crate) fn nimber_square<T: NimberRepr>(a: &Nimber<T>, lvl: Level) -> Nimber<T> {
+    #[cfg(feature = "tables")]
+    if lvl <= TABLE_LEVEL {
+        return table_square::<T>(a, lvl);
+    }
 
-    // impl
-    Nimber<T>: AddAssign<Nimber<T>>,
-{
     if lvl == 0 {
         return a.clone();
     }
 
     let lvl = lvl - 1;
 
-    let mut ah = high_part::<T>(&a, lvl);
-    let mut al = low_part::<T>(&a, lvl);
+    let mut ah = high_part::<T>(a, lvl);
+    let mut al = low_part::<T>(a, lvl);
 
     ah = nimber_square::<T>(&ah, lvl);
     al = nimber_square::<T>(&al, lvl);
@@ -213,34 +149,20 @@ where
     combine::<T>(&ah, &al, lvl)
 }
 
-fn nimber_sqrt<T: From<Smallest>>(a: &Nimber<T>, lvl: Level) -> Nimber<T>
-where
-    // high_part
-    for<'x> &'x Nimber<T>: Shr<Shift, Output = Nimber<T>>,
-
-    // low_part
-    T: Shl<Shift, Output = T> + Sub<Output = T>,
-    Nimber<T>: for<'y> BitAnd<&'y Nimber<T>, Output = Nimber<T>>,
-
-    // combine
-    for<'x> &'x Nimber<T>: Shl<Shift, Output = Nimber<T>>,
-    Nimber<T>: for<'y> BitOr<&'y Nimber<T>, Output = Nimber<T>>,
-
-    // nimber_mul_fermat
-    Nimber<T>: for<'y> AddAssign<&'y Nimber<T>>,
-    Nimber<T>: Clone,
+pub(crate) fn nimber_sqrt<T: NimberRepr>(a: &Nimber<T>, lvl: Level) -> Nimber<T> {
+    #[cfg(feature = "tables")]
+    if lvl <= TABLE_LEVEL {
+        return table_sqrt::<T>(a, lvl);
+    }
 
-    // impl
-    Nimber<T>: AddAssign<Nimber<T>>,
-{
     if lvl == 0 {
         return a.clone();
     }
 
     let lvl = lvl - 1;
 
-    let mut ah = high_part::<T>(&a, lvl);
-    let mut al = low_part::<T>(&a, lvl);
+    let mut ah = high_part::<T>(a, lvl);
+    let mut al = low_part::<T>(a, lvl);
 
     al += &nimber_mul_fermat::<T>(&ah, lvl);
 
@@ -250,38 +172,20 @@ where
     combine::<T>(&ah, &al, lvl)
 }
 
-fn nimber_inverse<T: From<Smallest>>(a: &Nimber<T>, lvl: Level) -> Nimber<T>
-where
-    // high_part
-    for<'x> &'x Nimber<T>: Shr<Shift, Output = Nimber<T>>,
-
-    // low_part
-    T: Shl<Shift, Output = T> + Sub<Output = T>,
-    Nimber<T>: for<'y> BitAnd<&'y Nimber<T>, Output = Nimber<T>>,
-
-    // combine
-    for<'x> &'x Nimber<T>: Shl<Shift, Output = Nimber<T>>,
-    Nimber<T>: for<'y> BitOr<&'y Nimber<T>, Output = Nimber<T>>,
-
-    // nimber_mul_fermat
-    Nimber<T>: for<'y> AddAssign<&'y Nimber<T>>,
-    Nimber<T>: Clone,
-
-    // nimber_mul_nimber
-    for<'x, 'y> &'x Nimber<T>: BitAnd<&'y Nimber<T>, Output = Nimber<T>>,
+pub(crate) fn nimber_inverse<T: NimberRepr>(a: &Nimber<T>, lvl: Level) -> Nimber<T> {
+    #[cfg(feature = "tables")]
+    if lvl <= TABLE_LEVEL {
+        return table_inverse::<T>(a, lvl);
+    }
 
-    // impl
-    Nimber<T>: AddAssign<Nimber<T>>,
-    Nimber<T>: for<'y> AddAssign<&'y Nimber<T>>,
-{
     if lvl == 0 {
         return a.clone();
     }
 
     let lvl = lvl - 1;
 
-    let ah = high_part::<T>(&a, lvl);
-    let al = low_part::<T>(&a, lvl);
+    let ah = high_part::<T>(a, lvl);
+    let al = low_part::<T>(a, lvl);
     let mut asum = ah.clone();
     asum += &al;
     let asum = asum;
@@ -297,51 +201,52 @@ where
     )
 }
 
-impl<T: From<Smallest>> Nimber<T>
-where
-    for<'x> &'x Nimber<T>: Shr<Shift, Output = Nimber<T>>,
-    T: Shl<Shift, Output = T> + Sub<Output = T>,
-    Nimber<T>: for<'y> BitAnd<&'y Nimber<T>, Output = Nimber<T>>,
-    for<'x> &'x Nimber<T>: Shl<Shift, Output = Nimber<T>>,
-    Nimber<T>: for<'y> BitOr<&'y Nimber<T>, Output = Nimber<T>>,
-    Nimber<T>: for<'y> AddAssign<&'y Nimber<T>>,
-    Nimber<T>: Clone,
-    for<'x, 'y> &'x Nimber<T>: BitAnd<&'y Nimber<T>, Output = Nimber<T>>,
-    Nimber<T>: AddAssign<Nimber<T>>,
-    Nimber<T>: for<'y> AddAssign<&'y Nimber<T>>,
-    for<'x, 'y> &'x Nimber<T>: BitAnd<&'y Nimber<T>, Output = Nimber<T>>,
-    for<'x> &'x Nimber<T>: Shl<Shift, Output = Nimber<T>>,
-    Nimber<T>: BitOrAssign<Nimber<T>> + PartialEq,
-{
+impl<T: NimberRepr> Nimber<T> {
     /// Takes the reciprocal (inverse) of a nimber, `1 / x`.
     ///
     /// The complexity is *O*(*n*^(log_2 3) \* log *n*).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
     #[inline]
     pub fn recip(&self) -> Nimber<T> {
-        nimber_inverse::<T>(&self, level::<T>(&self))
+        match self.checked_recip() {
+            Ok(inverse) => inverse,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Takes the reciprocal (inverse) of a nimber, `1 / x`,
+    /// returning [`NimberError::DivisionByZero`] if `self` is zero.
+    ///
+    /// The complexity is *O*(*n*^(log_2 3) \* log *n*).
+    #[inline]
+    pub fn checked_recip(&self) -> Result<Nimber<T>, NimberError> {
+        if *self == Nimber::from(T::from(0 as Smallest)) {
+            return Err(NimberError::DivisionByZero);
+        }
+
+        Ok(nimber_inverse::<T>(self, level::<T>(self)))
+    }
+
+    /// Divides two nimbers, `self / rhs`,
+    /// returning [`NimberError::DivisionByZero`] if `rhs` is zero.
+    ///
+    /// The complexity is *O*(*n*^(log_2 3) \* log *n*).
+    #[inline]
+    pub fn checked_div(&self, rhs: &Nimber<T>) -> Result<Nimber<T>, NimberError> {
+        Ok(self * rhs.checked_recip()?)
     }
 }
 
-impl<T: From<Smallest>> Nimber<T>
-where
-    for<'x> &'x Nimber<T>: Shr<Shift, Output = Nimber<T>>,
-    T: Shl<Shift, Output = T> + Sub<Output = T>,
-    Nimber<T>: for<'y> BitAnd<&'y Nimber<T>, Output = Nimber<T>>,
-    for<'x> &'x Nimber<T>: Shl<Shift, Output = Nimber<T>>,
-    Nimber<T>: for<'y> BitOr<&'y Nimber<T>, Output = Nimber<T>>,
-    Nimber<T>: for<'y> AddAssign<&'y Nimber<T>>,
-    Nimber<T>: Clone,
-    Nimber<T>: AddAssign<Nimber<T>>,
-    for<'x, 'y> &'x Nimber<T>: BitAnd<&'y Nimber<T>, Output = Nimber<T>>,
-    for<'x> &'x Nimber<T>: Shl<Shift, Output = Nimber<T>>,
-    Nimber<T>: BitOrAssign<Nimber<T>> + PartialEq,
-{
+impl<T: NimberRepr> Nimber<T> {
     /// Squares a nimber, `x * x`.
     ///
     /// The complexity is *O*(*n*^(log_2 3)), faster than the general multiplication.
     #[inline]
     pub fn square(&self) -> Nimber<T> {
-        nimber_square::<T>(&self, level::<T>(&self))
+        nimber_square::<T>(self, level::<T>(self))
     }
 
     /// Returns square root of a nimber.
@@ -349,38 +254,41 @@ where
     /// The complexity is *O*(*n*^(log_2 3)).
     #[inline]
     pub fn sqrt(&self) -> Nimber<T> {
-        nimber_sqrt::<T>(&self, level::<T>(&self))
+        nimber_sqrt::<T>(self, level::<T>(self))
     }
 }
 
-impl<'a, 'b, T: From<Smallest>> Div<&'b Nimber<T>> for &'a Nimber<T>
-where
-    &'a Nimber<T>: Mul<Nimber<T>, Output = Nimber<T>>,
-    for<'x> &'x Nimber<T>: Shr<Shift, Output = Nimber<T>>,
-    T: Shl<Shift, Output = T> + Sub<Output = T>,
-    Nimber<T>: for<'y> BitAnd<&'y Nimber<T>, Output = Nimber<T>>,
-    for<'x> &'x Nimber<T>: Shl<Shift, Output = Nimber<T>>,
-    Nimber<T>: for<'y> BitOr<&'y Nimber<T>, Output = Nimber<T>>,
-    Nimber<T>: for<'y> AddAssign<&'y Nimber<T>>,
-    Nimber<T>: Clone,
-    for<'x, 'y> &'x Nimber<T>: BitAnd<&'y Nimber<T>, Output = Nimber<T>>,
-    Nimber<T>: AddAssign<Nimber<T>>,
-    Nimber<T>: for<'y> AddAssign<&'y Nimber<T>>,
-    for<'x, 'y> &'x Nimber<T>: BitAnd<&'y Nimber<T>, Output = Nimber<T>>,
-    for<'x> &'x Nimber<T>: Shl<Shift, Output = Nimber<T>>,
-    Nimber<T>: BitOrAssign<Nimber<T>> + PartialEq,
-{
+impl<T: NimberRepr> Div<&Nimber<T>> for &Nimber<T> {
     type Output = Nimber<T>;
 
     /// Division of nimbers.
     /// Algorithm-wise it is an alias to the multiplication by the inverse.
     ///
     /// The complexity is *O*(*n*^(log_2 3) \* log *n*).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
     #[inline]
-    fn div(self, rhs: &'b Nimber<T>) -> Self::Output {
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: &Nimber<T>) -> Self::Output {
         self * rhs.recip()
     }
 }
 
 nimber_ref_binop!(impl Div, div);
 nimber_ref_binop_assign!(impl DivAssign, div_assign use Div, div);
+
+impl<T: NimberRepr> Product for Nimber<T> {
+    #[inline]
+    fn product<I: Iterator<Item = Nimber<T>>>(iter: I) -> Self {
+        iter.fold(Nimber::from(T::from(1 as Smallest)), |acc, a| &acc * &a)
+    }
+}
+
+impl<'a, T: NimberRepr> Product<&'a Nimber<T>> for Nimber<T> {
+    #[inline]
+    fn product<I: Iterator<Item = &'a Nimber<T>>>(iter: I) -> Self {
+        iter.fold(Nimber::from(T::from(1 as Smallest)), |acc, a| &acc * a)
+    }
+}
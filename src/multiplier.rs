@@ -0,0 +1,138 @@
+// Multiplication by a fixed nimber
+// The product by a fixed nimber a is GF(2)-linear, so a x is the sum of the products
+// of a by each byte of x in its place, and the products by all 256 values of each byte
+// are tabulated once; a product then takes a lookup per byte
+
+use super::Nimber;
+use crate::slice::{Nibbles, SliceKernel};
+use std::fmt::{self, Debug, Formatter};
+
+/// A nimber prepared for repeated multiplication, such as an entry of a generator matrix
+/// or a hash key, for [`Nim8`](crate::Nim8) to [`Nim128`](crate::Nim128) and `Nimber<usize>`.
+///
+/// It holds a table of 256 products for each byte of the type,
+/// from 256 bytes for `Nim8` to 64 KiB for `Nim128`,
+/// so that a product takes a table lookup per byte instead of a full multiplication.
+/// The slices of `Nim8` bytes are multiplied as in [`mul_slice`](crate::mul_slice).
+#[derive(Clone)]
+pub struct Multiplier<T> {
+    // the products by every value of each byte, from the least significant one
+    tables: Box<[[T; 256]]>,
+    // the tables of the vector kernel, for Nim8 only
+    nibbles: Option<Nibbles>,
+}
+
+impl<T> Multiplier<T>
+where
+    Self: From<Nimber<T>>,
+{
+    /// Tabulates the products by `a`.
+    ///
+    /// The complexity is *O*(*n*^(1 + log_2 3)).
+    #[inline]
+    pub fn new(a: Nimber<T>) -> Self {
+        Self::from(a)
+    }
+}
+
+macro_rules! nimber_multiplier {
+    ($($t:ty),*) => {
+        $(
+            impl From<Nimber<$t>> for Multiplier<$t> {
+                fn from(a: Nimber<$t>) -> Self {
+                    let mut tables = vec![[0; 256]; <$t>::BITS as usize / 8].into_boxed_slice();
+
+                    for (i, table) in tables.iter_mut().enumerate() {
+                        for j in 0..8 {
+                            table[1 << j] = (a * Nimber::<$t>::new(1 << (8 * i + j))).get();
+                        }
+
+                        // b without its lowest bit has already been multiplied
+                        for b in 1..256 {
+                            table[b] = table[b & (b - 1)] ^ table[b & b.wrapping_neg()];
+                        }
+                    }
+
+                    let nibbles = <$t>::nibbles(&tables[0]);
+
+                    Multiplier { tables, nibbles }
+                }
+            }
+
+            impl Multiplier<$t> {
+                /// Returns the nimber the products are by.
+                #[inline]
+                pub fn scalar(&self) -> Nimber<$t> {
+                    Nimber::<$t>::new(self.tables[0][1])
+                }
+
+                /// Multiplies `x` by the nimber, the same as `a * x`.
+                ///
+                /// The complexity is *O*(*n*).
+                #[inline]
+                pub fn apply(&self, x: Nimber<$t>) -> Nimber<$t> {
+                    Nimber::<$t>::new(self.apply_bits(x.get()))
+                }
+
+                /// Multiplies every element of `src` as a nimber by the nimber
+                /// and stores the products in `dst`.
+                ///
+                /// The complexity is *O*(*n* \* *m*) for slices of *m* elements.
+                ///
+                /// # Panics
+                ///
+                /// Panics if the slices have different lengths.
+                pub fn apply_slice(&self, src: &[$t], dst: &mut [$t]) {
+                    self.apply_kernel::<false>(src, dst);
+                }
+
+                /// Multiplies every element of `src` as a nimber by the nimber
+                /// and adds the products to `dst`.
+                ///
+                /// The complexity is *O*(*n* \* *m*) for slices of *m* elements.
+                ///
+                /// # Panics
+                ///
+                /// Panics if the slices have different lengths.
+                pub fn apply_add_slice(&self, src: &[$t], dst: &mut [$t]) {
+                    self.apply_kernel::<true>(src, dst);
+                }
+
+                #[inline]
+                fn apply_bits(&self, x: $t) -> $t {
+                    let mut y = 0;
+
+                    for (i, table) in self.tables.iter().enumerate() {
+                        y ^= table[(x >> (8 * i)) as u8 as usize];
+                    }
+
+                    y
+                }
+
+                // the vector kernel of the CPU if the type has one, then the tables for the rest
+                fn apply_kernel<const ADD: bool>(&self, src: &[$t], dst: &mut [$t]) {
+                    assert_eq!(src.len(), dst.len(), "the slices have different lengths");
+
+                    let done = match &self.nibbles {
+                        Some(nibbles) => <$t>::mul_simd::<ADD>(nibbles, src, dst),
+                        None => 0,
+                    };
+
+                    for (s, d) in src[done..].iter().zip(&mut dst[done..]) {
+                        let product = self.apply_bits(*s);
+
+                        *d = if ADD { *d ^ product } else { product };
+                    }
+                }
+            }
+
+            impl Debug for Multiplier<$t> {
+                fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    f.debug_tuple("Multiplier").field(&self.scalar()).finish()
+                }
+            }
+        )*
+    };
+}
+
+nimber_multiplier!(u8, u16, u32, u64, u128, usize);
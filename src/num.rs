@@ -0,0 +1,185 @@
+// Implementations of the num-traits traits
+// Nimbers form a field, so the numeric traits are the field operations,
+// with the remainder of the exact division always zero
+
+use super::{Nimber, NimberError};
+use crate::multiplication::Smallest;
+use crate::repr::NimberRepr;
+use num_traits::{Bounded, CheckedDiv, Inv, Num, One, Pow, Zero};
+use std::ops::{Rem, RemAssign};
+
+#[cfg(feature = "num-bigint")]
+use num_bigint::BigUint;
+
+impl<T: NimberRepr> Zero for Nimber<T> {
+    #[inline]
+    fn zero() -> Self {
+        Nimber::from(T::from(0 as Smallest))
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+}
+
+impl<T: NimberRepr> One for Nimber<T> {
+    #[inline]
+    fn one() -> Self {
+        Nimber::from(T::from(1 as Smallest))
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        *self == Self::one()
+    }
+}
+
+// required by Num, so only with this feature
+impl<T: NimberRepr> Rem<&Nimber<T>> for &Nimber<T> {
+    type Output = Nimber<T>;
+
+    /// Remainder of the division of nimbers.
+    /// The division in a field is exact, so the remainder is always zero.
+    ///
+    /// The complexity is *O*(*n*).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    #[inline]
+    fn rem(self, rhs: &Nimber<T>) -> Self::Output {
+        let zero = Nimber::from(T::from(0 as Smallest));
+
+        if *rhs == zero {
+            panic!("{}", NimberError::DivisionByZero);
+        }
+
+        zero
+    }
+}
+
+nimber_ref_binop!(impl Rem, rem);
+nimber_ref_binop_assign!(impl RemAssign, rem_assign use Rem, rem);
+
+impl<T: NimberRepr + Num> Num for Nimber<T> {
+    type FromStrRadixErr = NimberError;
+
+    /// Parses the integer representation of a nimber in the given radix,
+    /// failing with [`NimberError::Parse`].
+    #[inline]
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        T::from_str_radix(str, radix)
+            .map(Nimber::from)
+            .map_err(|_| NimberError::Parse)
+    }
+}
+
+impl<T: NimberRepr> Inv for Nimber<T> {
+    type Output = Nimber<T>;
+
+    /// See [`recip`](Nimber::recip).
+    #[inline]
+    fn inv(self) -> Self::Output {
+        self.recip()
+    }
+}
+
+impl<T: NimberRepr> Inv for &Nimber<T> {
+    type Output = Nimber<T>;
+
+    /// See [`recip`](Nimber::recip).
+    #[inline]
+    fn inv(self) -> Self::Output {
+        self.recip()
+    }
+}
+
+impl<T: NimberRepr> CheckedDiv for Nimber<T> {
+    #[inline]
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        Nimber::checked_div(self, v).ok()
+    }
+}
+
+macro_rules! nimber_pow {
+    ($($e:ty => $method:ident as $p:ty),*) => {
+        $(
+            impl<T: NimberRepr> Pow<$e> for Nimber<T> {
+                type Output = Nimber<T>;
+
+                #[doc = concat!("See [`", stringify!($method), "`](Nimber::", stringify!($method), ").")]
+                #[inline]
+                fn pow(self, rhs: $e) -> Self::Output {
+                    Nimber::$method(&self, rhs as $p)
+                }
+            }
+
+            impl<'a, T: NimberRepr> Pow<$e> for &'a Nimber<T> {
+                type Output = Nimber<T>;
+
+                #[doc = concat!("See [`", stringify!($method), "`](Nimber::", stringify!($method), ").")]
+                #[inline]
+                fn pow(self, rhs: $e) -> Self::Output {
+                    Nimber::$method(self, rhs as $p)
+                }
+            }
+        )*
+    };
+}
+
+nimber_pow!(
+    u8 => pow as u64,
+    u16 => pow as u64,
+    u32 => pow as u64,
+    u64 => pow as u64,
+    usize => pow as u64,
+    u128 => pow_u128 as u128,
+    i8 => powi as i64,
+    i16 => powi as i64,
+    i32 => powi as i64,
+    i64 => powi as i64,
+    isize => powi as i64
+);
+
+#[cfg(feature = "num-bigint")]
+impl<'b, T: NimberRepr> Pow<&'b BigUint> for Nimber<T> {
+    type Output = Nimber<T>;
+
+    /// See [`pow_big`](Nimber::pow_big).
+    #[inline]
+    fn pow(self, rhs: &'b BigUint) -> Self::Output {
+        self.pow_big(rhs)
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl<'b, T: NimberRepr> Pow<&'b BigUint> for &Nimber<T> {
+    type Output = Nimber<T>;
+
+    /// See [`pow_big`](Nimber::pow_big).
+    #[inline]
+    fn pow(self, rhs: &'b BigUint) -> Self::Output {
+        self.pow_big(rhs)
+    }
+}
+
+macro_rules! nimber_bounded {
+    ($($t:ty),*) => {
+        $(
+            impl Bounded for Nimber<$t> {
+                #[inline]
+                fn min_value() -> Self {
+                    Nimber::from(<$t>::MIN)
+                }
+
+                #[inline]
+                fn max_value() -> Self {
+                    Nimber::from(<$t>::MAX)
+                }
+            }
+        )*
+    };
+}
+
+nimber_bounded!(u8, u16, u32, u64, u128, usize);
@@ -0,0 +1,123 @@
+// Multiplicative order of nimbers
+// The multiplicative group of the subfield at level k has order 2^(2^k) - 1,
+// which is the product of the Fermat numbers F_0, ..., F_(k-1)
+
+use super::Nimber;
+use crate::multiplication::{level, Level, Smallest};
+use crate::power::group_order;
+use crate::repr::NimberRepr;
+
+// prime factors of the Fermat numbers F_i = 2^(2^i) + 1, i < 7
+pub(crate) const FERMAT_FACTORS: [&[u128]; 7] = [
+    &[3],
+    &[5],
+    &[17],
+    &[257],
+    &[65537],
+    &[641, 6_700_417],
+    &[274_177, 67_280_421_310_721],
+];
+
+// prime factors of the order of the multiplicative group at level lvl <= 7
+pub(crate) fn group_order_factors(lvl: Level) -> impl Iterator<Item = u128> {
+    FERMAT_FACTORS[..lvl as usize]
+        .iter()
+        .flat_map(|factors| factors.iter().copied())
+}
+
+// inverse of a modulo m for coprime a and m, where m may be as large as u128::MAX;
+// the Bezout coefficients alternate in sign, so only their absolute values are kept
+pub(crate) fn inv_mod(a: u128, m: u128) -> u128 {
+    if m == 1 {
+        return 0;
+    }
+
+    let (mut r0, mut r1) = (m, a % m);
+    let (mut t0, mut t1) = (0u128, 1u128);
+    let mut positive = false;
+
+    while r1 != 0 {
+        let q = r0 / r1;
+
+        (r0, r1) = (r1, r0 - q * r1);
+        (t0, t1) = (t1, t0 + q * t1);
+        positive = !positive;
+    }
+
+    if positive {
+        t0
+    } else {
+        m - t0
+    }
+}
+
+impl<T: NimberRepr> Nimber<T> {
+    /// Returns the multiplicative order of a nimber,
+    /// the smallest positive `n` such that `x.pow_u128(n)` is `1`.
+    ///
+    /// Returns `None` for zero and for nimbers which do not fit in 128 bits.
+    pub fn multiplicative_order(&self) -> Option<u128> {
+        if *self == Nimber::from(T::from(0 as Smallest)) {
+            return None;
+        }
+
+        let lvl = level::<T>(self);
+
+        if lvl > 7 {
+            return None;
+        }
+
+        let one = Nimber::from(T::from(1 as Smallest));
+        let mut order = group_order(lvl);
+
+        for p in group_order_factors(lvl) {
+            if self.pow_u128(order / p) == one {
+                order /= p;
+            }
+        }
+
+        Some(order)
+    }
+}
+
+macro_rules! nimber_primitive {
+    ($($t:ty),*) => {
+        $(
+            impl Nimber<$t> {
+                /// Checks if a nimber generates the multiplicative group of the whole field,
+                /// that is, its multiplicative order is `2^BITS - 1`.
+                #[inline]
+                pub fn is_primitive(&self) -> bool {
+                    let lvl = <$t>::BITS.trailing_zeros() as Level;
+
+                    self.multiplicative_order() == Some(group_order(lvl))
+                }
+            }
+        )*
+    };
+}
+
+nimber_primitive!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! nimber_primitive_element {
+    ($($t:ty => $g:expr),*) => {
+        $(
+            impl Nimber<$t> {
+                /// Returns the smallest primitive element of the field,
+                /// a generator of its multiplicative group.
+                #[inline]
+                pub fn primitive_element() -> Self {
+                    Nimber::from($g)
+                }
+            }
+        )*
+    };
+}
+
+nimber_primitive_element!(
+    u8 => 18,
+    u16 => 258,
+    u32 => 65540,
+    u64 => 0x1_0000_0006,
+    u128 => 0x1_0000_0000_0000_0002
+);
@@ -0,0 +1,68 @@
+// Parsing of nimbers from strings
+// A literal is an optional star of the game-theory notation, then an optional
+// 0x or 0b radix prefix, then the digits; the digits are checked here so that
+// every backing type accepts exactly the same literals
+
+use super::{Nimber, NimberError};
+use std::str::FromStr;
+
+#[cfg(feature = "num-bigint")]
+use num_bigint::BigUint;
+
+// splits a literal into its digits and their radix
+fn literal_digits(s: &str) -> Result<(&str, u32), NimberError> {
+    let s = s.strip_prefix('*').unwrap_or(s);
+
+    let (digits, radix) = if let Some(digits) = s.strip_prefix("0x") {
+        (digits, 16)
+    } else if let Some(digits) = s.strip_prefix("0b") {
+        (digits, 2)
+    } else {
+        (s, 10)
+    };
+
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return Err(NimberError::Parse);
+    }
+
+    Ok((digits, radix))
+}
+
+macro_rules! nimber_from_str {
+    ($($t:ty),*) => {
+        $(
+            /// Parses a nimber from a decimal literal, a literal with a `0x` or `0b` prefix,
+            /// or any of these in the star notation, such as `*5`,
+            /// failing with [`NimberError::Parse`] if it is invalid or does not fit.
+            impl FromStr for Nimber<$t> {
+                type Err = NimberError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let (digits, radix) = literal_digits(s)?;
+
+                    <$t>::from_str_radix(digits, radix)
+                        .map(Nimber::from)
+                        .map_err(|_| NimberError::Parse)
+                }
+            }
+        )*
+    };
+}
+
+nimber_from_str!(u8, u16, u32, u64, u128, usize);
+
+/// Parses a nimber from a decimal literal, a literal with a `0x` or `0b` prefix,
+/// or any of these in the star notation, such as `*5`,
+/// failing with [`NimberError::Parse`] if it is invalid.
+#[cfg(feature = "num-bigint")]
+impl FromStr for Nimber<BigUint> {
+    type Err = NimberError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (digits, radix) = literal_digits(s)?;
+
+        BigUint::parse_bytes(digits.as_bytes(), radix)
+            .map(Nimber::from)
+            .ok_or(NimberError::Parse)
+    }
+}
@@ -0,0 +1,142 @@
+// Nimber exponentiation
+// Square-and-multiply with the exponent reduced modulo the order 2^(2^k) - 1
+// of the multiplicative group of the smallest subfield containing the base.
+// The products go through the multiplication of the backing type, and so do the squares
+// when it runs on the CPU multiplier; otherwise the recursive square is cheaper
+
+use super::Nimber;
+use crate::multiplication::{level, nimber_square, Level, Smallest};
+use crate::repr::NimberRepr;
+#[cfg(feature = "tables")]
+use crate::table::{table_pow, TABLE_LEVEL};
+
+#[cfg(feature = "num-bigint")]
+use num_bigint::BigUint;
+
+// the largest level whose group order fits in u128
+pub(crate) const MAX_U128_LEVEL: Level = 7;
+
+// order of the multiplicative group of the subfield at level lvl <= 7
+#[inline]
+pub(crate) fn group_order(lvl: Level) -> u128 {
+    if lvl == MAX_U128_LEVEL {
+        u128::MAX
+    } else {
+        (1u128 << (1u32 << lvl)) - 1
+    }
+}
+
+// raises a to the power given by its bits, the most significant first
+fn nimber_pow<T: NimberRepr, I>(a: &Nimber<T>, bits: I, lvl: Level) -> Nimber<T>
+where
+    I: Iterator<Item = bool>,
+{
+    let mut ans = Nimber::from(T::from(1 as Smallest));
+    let hardware = T::mul_in_hardware();
+
+    for bit in bits {
+        ans = if hardware {
+            &ans * &ans
+        } else {
+            nimber_square::<T>(&ans, lvl)
+        };
+
+        if bit {
+            ans = &ans * a;
+        }
+    }
+
+    ans
+}
+
+// raises a to the power exp at level lvl
+#[inline]
+pub(crate) fn nimber_pow_u128<T: NimberRepr>(a: &Nimber<T>, exp: u128, lvl: Level) -> Nimber<T> {
+    #[cfg(feature = "tables")]
+    if lvl <= TABLE_LEVEL {
+        return table_pow::<T>(a, exp, lvl);
+    }
+
+    let len = 128 - exp.leading_zeros();
+
+    nimber_pow::<T, _>(a, (0..len).rev().map(|i| (exp >> i) & 1 == 1), lvl)
+}
+
+impl<T: NimberRepr> Nimber<T> {
+    /// Raises a nimber to the power of `exp`, using exponentiation by squaring.
+    ///
+    /// `0.pow(0)` is `1`.
+    ///
+    /// The complexity is *O*(*n*^(log_2 3) \* log *n* \* min(*n*, log *exp*)).
+    #[inline]
+    pub fn pow(&self, exp: u64) -> Nimber<T> {
+        self.pow_u128(exp as u128)
+    }
+
+    /// Raises a nimber to the power of `exp`, using exponentiation by squaring.
+    ///
+    /// `0.pow_u128(0)` is `1`.
+    ///
+    /// The complexity is *O*(*n*^(log_2 3) \* log *n* \* min(*n*, log *exp*)).
+    pub fn pow_u128(&self, exp: u128) -> Nimber<T> {
+        let zero = Nimber::from(T::from(0 as Smallest));
+
+        if *self == zero {
+            return if exp == 0 {
+                Nimber::from(T::from(1 as Smallest))
+            } else {
+                zero
+            };
+        }
+
+        let lvl = level::<T>(self);
+        let exp = if lvl <= MAX_U128_LEVEL {
+            exp % group_order(lvl)
+        } else {
+            exp
+        };
+
+        nimber_pow_u128::<T>(self, exp, lvl)
+    }
+
+    /// Raises a nimber to the power of `exp`, using exponentiation by squaring.
+    /// Negative exponents raise the reciprocal.
+    ///
+    /// The complexity is *O*(*n*^(log_2 3) \* log *n* \* min(*n*, log *exp*)).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero and `exp` is negative.
+    #[inline]
+    pub fn powi(&self, exp: i64) -> Nimber<T> {
+        if exp < 0 {
+            self.recip().pow(exp.unsigned_abs())
+        } else {
+            self.pow(exp as u64)
+        }
+    }
+
+    /// Raises a nimber to the power of an arbitrarily large `exp`,
+    /// using exponentiation by squaring.
+    ///
+    /// `0.pow_big(0)` is `1`.
+    ///
+    /// The complexity is *O*(*n*^(log_2 3) \* log *n* \* min(*n*, log *exp*)).
+    #[cfg(feature = "num-bigint")]
+    pub fn pow_big(&self, exp: &BigUint) -> Nimber<T> {
+        let zero = Nimber::from(T::from(0 as Smallest));
+
+        if *self == zero {
+            return if *exp == BigUint::from(0u8) {
+                Nimber::from(T::from(1 as Smallest))
+            } else {
+                zero
+            };
+        }
+
+        let lvl = level::<T>(self);
+        let exp = exp % ((BigUint::from(1u8) << (1usize << lvl)) - 1u8);
+
+        nimber_pow::<T, _>(self, (0..exp.bits()).rev().map(|i| exp.bit(i)), lvl)
+    }
+}
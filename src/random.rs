@@ -0,0 +1,101 @@
+// Random sampling of nimbers
+// The subfield at level k is exactly the set of nimbers below 2^(2^k),
+// so uniform sampling from it is uniform sampling of the low 2^k bits
+
+use super::Nimber;
+use crate::multiplication::Level;
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+
+#[cfg(feature = "num-bigint")]
+use num_bigint::{BigUint, RandBigInt};
+
+/// The uniform distribution over the nonzero nimbers of the whole field of a fixed-width type,
+/// the elements of its multiplicative group.
+#[derive(Clone, Copy, Debug)]
+pub struct UniformNonzero;
+
+/// The uniform distribution over the subfield at a level,
+/// that is, over the nimbers below `2^(2^level)`, or over its nonzero nimbers.
+///
+/// Sampling a fixed-width nimber panics if the level exceeds the level of the whole field of the type.
+/// Unbounded nimbers can be sampled from any level.
+#[derive(Clone, Copy, Debug)]
+pub struct UniformSubfield {
+    level: Level,
+    nonzero: bool,
+}
+
+impl UniformSubfield {
+    /// Creates the uniform distribution over the subfield at `level`.
+    #[inline]
+    pub fn new(level: Level) -> Self {
+        UniformSubfield {
+            level,
+            nonzero: false,
+        }
+    }
+
+    /// Creates the uniform distribution over the nonzero nimbers of the subfield at `level`.
+    #[inline]
+    pub fn nonzero(level: Level) -> Self {
+        UniformSubfield {
+            level,
+            nonzero: true,
+        }
+    }
+}
+
+macro_rules! nimber_distribution {
+    ($($t:ty),*) => {
+        $(
+            /// Samples a nimber uniformly from the whole field of the type.
+            impl Distribution<Nimber<$t>> for Standard {
+                #[inline]
+                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Nimber<$t> {
+                    Nimber::from(rng.gen::<$t>())
+                }
+            }
+
+            impl Distribution<Nimber<$t>> for UniformNonzero {
+                #[inline]
+                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Nimber<$t> {
+                    Nimber::from(rng.gen_range(1..=<$t>::MAX))
+                }
+            }
+
+            impl Distribution<Nimber<$t>> for UniformSubfield {
+                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Nimber<$t> {
+                    let bits = 1u32.checked_shl(self.level.into()).unwrap_or(u32::MAX);
+
+                    assert!(bits <= <$t>::BITS, "the level exceeds the field of the type");
+
+                    let max = <$t>::MAX >> (<$t>::BITS - bits);
+
+                    if self.nonzero {
+                        Nimber::from(rng.gen_range(1..=max))
+                    } else {
+                        Nimber::from(rng.gen::<$t>() & max)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+nimber_distribution!(u8, u16, u32, u64, u128, usize);
+
+#[cfg(feature = "num-bigint")]
+impl Distribution<Nimber<BigUint>> for UniformSubfield {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Nimber<BigUint> {
+        let bits = 1u64 << self.level;
+
+        if self.nonzero {
+            let bound = BigUint::from(1u8) << bits;
+
+            Nimber::from(rng.gen_biguint_range(&BigUint::from(1u8), &bound))
+        } else {
+            Nimber::from(rng.gen_biguint(bits))
+        }
+    }
+}
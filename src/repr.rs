@@ -0,0 +1,215 @@
+// Backing types of nimbers
+// Nimbers below 2^(2^k) form the subfield at level k,
+// so a fixed-width integer type holds the whole field at level log_2 BITS
+
+use super::Nimber;
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+use crate::clmul;
+#[cfg(feature = "num-bigint")]
+use crate::multiplication::nimber_mul_nimber;
+use crate::multiplication::{level, Level, Shift, Smallest};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Shl, Shr, Sub};
+
+#[cfg(feature = "num-bigint")]
+use num_bigint::BigUint;
+
+pub(crate) mod sealed {
+    use crate::multiplication::Shift;
+    use crate::Nimber;
+
+    // hooks of the arithmetic specific to the backing type,
+    // out of reach of the users since the trait cannot be named
+    pub trait Sealed: Sized {
+        // the product, by the const fn arithmetic for the fixed-width types
+        // or the carry-less multiplication of the CPU for Nim64 and Nim128
+        fn nimber_mul(a: &Nimber<Self>, b: &Nimber<Self>) -> Nimber<Self>;
+
+        // whether nimber_mul runs on the CPU multiplier, which then also beats
+        // the recursive square
+        #[inline]
+        fn mul_in_hardware() -> bool {
+            false
+        }
+
+        // the bit operations on references, which the operator supertraits
+        // cannot express without restating the bounds at every use;
+        // they spare the recursion a clone of an unbounded integer at every step
+        fn shr_ref(&self, rhs: Shift) -> Self;
+
+        fn shl_ref(&self, rhs: Shift) -> Self;
+
+        fn bitand_ref(&self, rhs: &Self) -> Self;
+
+        fn bitor_ref(&self, rhs: &Self) -> Self;
+
+        // the low 16 bits, for the table-driven arithmetic of the nimbers below 2^16
+        #[cfg(feature = "tables")]
+        fn to_u16(&self) -> u16;
+
+        // converts a value fitting in the type
+        #[cfg(feature = "tables")]
+        fn from_u16(x: u16) -> Self;
+    }
+}
+
+/// Unsigned integer types which can back a nimber:
+/// `u8`, `u16`, `u32`, `u64`, `u128`, `usize` and `BigUint` with the `num-bigint` feature.
+///
+/// The trait is sealed. It gathers the bit operations the arithmetic is built from,
+/// so generic code over `Nimber<T>` only needs the bound `T: NimberRepr`.
+pub trait NimberRepr:
+    sealed::Sealed
+    + Clone
+    + Eq
+    + Ord
+    + Hash
+    + Debug
+    + From<Smallest>
+    + Shl<Shift, Output = Self>
+    + Shr<Shift, Output = Self>
+    + Sub<Output = Self>
+    + BitAnd<Output = Self>
+    + for<'a> BitAnd<&'a Self, Output = Self>
+    + BitAndAssign
+    + for<'a> BitAndAssign<&'a Self>
+    + BitOr<Output = Self>
+    + for<'a> BitOr<&'a Self, Output = Self>
+    + BitOrAssign
+    + for<'a> BitOrAssign<&'a Self>
+    + BitXor<Output = Self>
+    + for<'a> BitXor<&'a Self, Output = Self>
+    + BitXorAssign
+    + for<'a> BitXorAssign<&'a Self>
+{
+    /// Level of the whole field held by the type, `None` for unbounded types.
+    const MAX_LEVEL: Option<Level>;
+}
+
+macro_rules! nimber_repr {
+    ($($t:ty),*) => {
+        $(
+            nimber_repr!($t: |a: &Nimber<$t>, b: &Nimber<$t>| a.const_mul(*b), false);
+        )*
+    };
+    ($($t:ty: $mul:expr, $hardware:expr),*) => {
+        $(
+            impl sealed::Sealed for $t {
+                #[inline]
+                fn nimber_mul(a: &Nimber<$t>, b: &Nimber<$t>) -> Nimber<$t> {
+                    ($mul)(a, b)
+                }
+
+                #[inline]
+                fn mul_in_hardware() -> bool {
+                    $hardware
+                }
+
+                #[inline]
+                fn shr_ref(&self, rhs: Shift) -> Self {
+                    *self >> rhs
+                }
+
+                #[inline]
+                fn shl_ref(&self, rhs: Shift) -> Self {
+                    *self << rhs
+                }
+
+                #[inline]
+                fn bitand_ref(&self, rhs: &Self) -> Self {
+                    *self & *rhs
+                }
+
+                #[inline]
+                fn bitor_ref(&self, rhs: &Self) -> Self {
+                    *self | *rhs
+                }
+
+                #[cfg(feature = "tables")]
+                #[inline]
+                fn to_u16(&self) -> u16 {
+                    *self as u16
+                }
+
+                #[cfg(feature = "tables")]
+                #[inline]
+                fn from_u16(x: u16) -> Self {
+                    x as $t
+                }
+            }
+
+            impl NimberRepr for $t {
+                const MAX_LEVEL: Option<Level> = Some(<$t>::BITS.trailing_zeros() as Level);
+            }
+        )*
+    };
+}
+
+nimber_repr!(u8, u16, u32, usize);
+
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+nimber_repr!(
+    u64: clmul::nimber_mul_u64, clmul::detected(),
+    u128: clmul::nimber_mul_u128, clmul::detected()
+);
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+nimber_repr!(u64, u128);
+
+#[cfg(feature = "num-bigint")]
+impl sealed::Sealed for BigUint {
+    #[inline]
+    fn nimber_mul(a: &Nimber<BigUint>, b: &Nimber<BigUint>) -> Nimber<BigUint> {
+        let lvl = level::<BigUint>(&Nimber::from(a.x.bitor_ref(&b.x)));
+
+        nimber_mul_nimber::<BigUint>(a, b, lvl)
+    }
+
+    #[inline]
+    fn shr_ref(&self, rhs: Shift) -> Self {
+        self >> rhs
+    }
+
+    #[inline]
+    fn shl_ref(&self, rhs: Shift) -> Self {
+        self << rhs
+    }
+
+    #[inline]
+    fn bitand_ref(&self, rhs: &Self) -> Self {
+        self & rhs
+    }
+
+    #[inline]
+    fn bitor_ref(&self, rhs: &Self) -> Self {
+        self | rhs
+    }
+
+    #[cfg(feature = "tables")]
+    #[inline]
+    fn to_u16(&self) -> u16 {
+        self.iter_u32_digits().next().unwrap_or(0) as u16
+    }
+
+    #[cfg(feature = "tables")]
+    #[inline]
+    fn from_u16(x: u16) -> Self {
+        BigUint::from(x)
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl NimberRepr for BigUint {
+    const MAX_LEVEL: Option<Level> = None;
+}
+
+// level of the field which a nimber is considered an element of:
+// the whole field of a fixed-width type, or the smallest subfield containing it otherwise
+#[inline]
+pub(crate) fn field_level<T: NimberRepr>(a: &Nimber<T>) -> Level {
+    match T::MAX_LEVEL {
+        Some(lvl) => lvl,
+        None => level::<T>(a),
+    }
+}
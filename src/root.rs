@@ -0,0 +1,198 @@
+// Roots of nimbers
+// For k >= 1 the multiplicative group at level k has order 3 m, where m = F_1 ... F_(k-1)
+// is coprime to 3, so every cube has exactly three cube roots:
+// a^(1/3 mod m) times the cube roots of unity 1, 2 and 3
+// In general the group order N = F_0 ... F_(k-1) is squarefree, so for g = gcd(n, N)
+// the Adleman-Manders-Miller algorithm stops after its first step:
+// a is an n-th power if and only if a^(N / g) = 1, one root is a^(1/n mod N/g),
+// and the others are obtained by multiplying it by the g-th roots of unity
+
+use super::Nimber;
+use crate::multiplication::{nimber_mul_nimber, Level, Shift, Smallest};
+use crate::order::{inv_mod, FERMAT_FACTORS};
+use crate::power::{group_order, nimber_pow_u128, MAX_U128_LEVEL};
+use crate::repr::{field_level, NimberRepr};
+
+// all cube roots of a at level lvl <= 7
+pub(crate) fn nimber_cbrt<T: NimberRepr>(a: &Nimber<T>, lvl: Level) -> Vec<Nimber<T>> {
+    assert!(
+        lvl <= MAX_U128_LEVEL,
+        "cube roots are supported for nimbers of at most 128 bits"
+    );
+
+    // cubing is a bijection of GF(2), and 0 is a triple root of x^3
+    if lvl == 0 || *a == Nimber::from(T::from(0 as Smallest)) {
+        return vec![a.clone()];
+    }
+
+    let m = group_order(lvl) / 3;
+
+    if nimber_pow_u128::<T>(a, m, lvl) != Nimber::from(T::from(1 as Smallest)) {
+        return Vec::new();
+    }
+
+    let exp = if m % 3 == 1 {
+        (2 * m + 1) / 3
+    } else {
+        (m + 1) / 3
+    };
+    let root = nimber_pow_u128::<T>(a, exp, lvl);
+    let omega = Nimber::from(T::from(2 as Smallest));
+    let omega_root = nimber_mul_nimber::<T>(&root, &omega, lvl);
+    let mut omega2_root = omega_root.clone();
+    omega2_root += &root;
+
+    vec![root, omega_root, omega2_root]
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+// a primitive p-th root of unity for a prime p dividing F_i, found at level i + 1
+fn nimber_unity_root<T: NimberRepr>(p: u128, i: Level) -> Nimber<T> {
+    let one = Nimber::from(T::from(1 as Smallest));
+    let exp = group_order(i + 1) / p;
+
+    // the elements of the subfield at level i lie in a subgroup of order coprime to p,
+    // so the candidates are taken from outside of it
+    (0..=Smallest::MAX)
+        .map(|k| {
+            let mut c = Nimber::from(one.x.shl_ref((1 as Shift) << i));
+            c += Nimber::from(T::from(k));
+            nimber_pow_u128::<T>(&c, exp, i + 1)
+        })
+        .find(|zeta| *zeta != one)
+        .expect("a primitive root of unity exists at the next level")
+}
+
+// one n-th root of a at level lvl <= 7, a primitive g-th root of unity
+// and the number g of roots, which is gcd(n, order) for nonzero a
+fn nimber_nth_root_coset<T: NimberRepr>(
+    a: &Nimber<T>,
+    n: u64,
+    lvl: Level,
+) -> Option<(Nimber<T>, Nimber<T>, u128)> {
+    assert!(n != 0, "the zeroth root is undefined");
+    assert!(
+        lvl <= MAX_U128_LEVEL,
+        "roots are supported for nimbers of at most 128 bits"
+    );
+
+    let one = Nimber::from(T::from(1 as Smallest));
+
+    if *a == Nimber::from(T::from(0 as Smallest)) {
+        return Some((a.clone(), one, 1));
+    }
+
+    let order = group_order(lvl);
+    let n = n as u128 % order;
+    let g = gcd(n, order);
+    let m = order / g;
+
+    if nimber_pow_u128::<T>(a, m, lvl) != one {
+        return None;
+    }
+
+    // the order of a divides m, which is coprime to n
+    let root = nimber_pow_u128::<T>(a, inv_mod(n % m, m), lvl);
+    let mut zeta = one;
+
+    for (i, factors) in FERMAT_FACTORS[..lvl as usize].iter().enumerate() {
+        for &p in factors.iter() {
+            if g % p == 0 {
+                zeta = nimber_mul_nimber::<T>(&zeta, &nimber_unity_root::<T>(p, i as Level), lvl);
+            }
+        }
+    }
+
+    Some((root, zeta, g))
+}
+
+// all n-th roots of a at level lvl <= 7
+pub(crate) fn nimber_nth_root<T: NimberRepr>(a: &Nimber<T>, n: u64, lvl: Level) -> Vec<Nimber<T>> {
+    let (root, zeta, g) = match nimber_nth_root_coset::<T>(a, n, lvl) {
+        Some(coset) => coset,
+        None => return Vec::new(),
+    };
+
+    let mut roots = vec![root];
+
+    for _ in 1..g {
+        let next = nimber_mul_nimber::<T>(&roots[roots.len() - 1], &zeta, lvl);
+        roots.push(next);
+    }
+
+    roots
+}
+
+impl<T: NimberRepr> Nimber<T> {
+    /// Returns all cube roots of a nimber.
+    ///
+    /// Since 3 divides the order of the multiplicative group of every field but GF(2),
+    /// a nonzero nimber has either three distinct cube roots or none.
+    /// Zero has the single cube root zero, and so does one for unbounded nimbers,
+    /// whose smallest subfield is then GF(2); in the field of a fixed-width type,
+    /// one has the three cube roots 1, 2 and 3.
+    ///
+    /// The roots are searched for in the whole field of the type,
+    /// or in the smallest subfield containing the nimber for unbounded nimbers.
+    ///
+    /// The complexity is *O*(*n*^(1 + log_2 3) \* log *n*).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field does not fit in 128 bits.
+    #[inline]
+    pub fn cbrt(&self) -> Vec<Nimber<T>> {
+        nimber_cbrt::<T>(self, field_level::<T>(self))
+    }
+
+    /// Returns all `n`-th roots of a nimber.
+    ///
+    /// A nonzero nimber has either no `n`-th roots or exactly `gcd(n, 2^BITS - 1)` of them,
+    /// so for `n` coprime to the order of the multiplicative group the root is unique.
+    /// Zero has the single root zero.
+    ///
+    /// The roots are searched for in the whole field of the type,
+    /// or in the smallest subfield containing the nimber for unbounded nimbers.
+    ///
+    /// The complexity is that of [`pow_u128`](Nimber::pow_u128) plus a multiplication per root.
+    /// All the roots are stored, and their number grows with the width of the type:
+    /// 16,843,009 for `Nim64` and `n = 257 * 65537`, or `2^64 - 1` for `n = u64::MAX`,
+    /// which does not fit in memory. [`nth_root_coset`](Nimber::nth_root_coset) returns
+    /// a single root and a generator of the others instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero or if the field does not fit in 128 bits.
+    #[inline]
+    pub fn nth_root(&self, n: u64) -> Vec<Nimber<T>> {
+        nimber_nth_root::<T>(self, n, field_level::<T>(self))
+    }
+
+    /// Returns an `n`-th root `r` of a nimber and a primitive `g`-th root of unity `z`,
+    /// where `g = gcd(n, 2^BITS - 1)`, or `None` if the nimber has no `n`-th root.
+    ///
+    /// The `n`-th roots of a nonzero nimber are then the `g` nimbers `r * z^i` for `i < g`,
+    /// the ones [`nth_root`](Nimber::nth_root) returns, without storing them.
+    /// Zero gives the pair `(0, 1)`.
+    ///
+    /// The roots are searched for in the whole field of the type,
+    /// or in the smallest subfield containing the nimber for unbounded nimbers.
+    ///
+    /// The complexity is that of [`pow_u128`](Nimber::pow_u128).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero or if the field does not fit in 128 bits.
+    #[inline]
+    pub fn nth_root_coset(&self, n: u64) -> Option<(Nimber<T>, Nimber<T>)> {
+        nimber_nth_root_coset::<T>(self, n, field_level::<T>(self))
+            .map(|(root, zeta, _)| (root, zeta))
+    }
+}
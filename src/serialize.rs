@@ -0,0 +1,70 @@
+// Serialization of nimbers
+// A nimber is serialized as its backing integer by default;
+// the hex module switches human-readable formats to hex strings
+
+use super::Nimber;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes a nimber as its backing integer in every format.
+///
+/// `Nim128` is a 128-bit integer, which some human-readable formats cannot represent
+/// exactly, and `BigNim` uses the encoding of `BigUint`, a sequence of digits;
+/// see [`serde_hex`](crate::serde_hex) for a string encoding.
+impl<T: Serialize> Serialize for Nimber<T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.x.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Nimber<T> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Nimber::from)
+    }
+}
+
+/// Serialization of a nimber as a hex string, such as `"0x1f"`, in human-readable formats
+/// and as its backing integer in binary formats, for use with `#[serde(with = "nimber::serde_hex")]`.
+///
+/// Deserialization from a human-readable format accepts every literal of
+/// [`from_str`](std::str::FromStr::from_str), so decimal and star notation strings are valid too.
+pub mod hex {
+    use super::Nimber;
+    use crate::NimberError;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::fmt::LowerHex;
+    use std::str::FromStr;
+
+    /// Serializes a nimber as a hex string in human-readable formats
+    /// and as its backing integer otherwise.
+    pub fn serialize<T, S>(a: &Nimber<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize + LowerHex,
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&format_args!("{:#x}", a))
+        } else {
+            a.x.serialize(serializer)
+        }
+    }
+
+    /// Deserializes a nimber from a string in human-readable formats
+    /// and from its backing integer otherwise.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Nimber<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        Nimber<T>: FromStr<Err = NimberError>,
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            String::deserialize(deserializer)?
+                .parse()
+                .map_err(D::Error::custom)
+        } else {
+            T::deserialize(deserializer).map(Nimber::from)
+        }
+    }
+}
@@ -0,0 +1,277 @@
+// Multiplication of byte slices by a fixed Nim8
+// The product by a fixed scalar is linear, so s x = s (x & 0x0f) + s (x & 0xf0):
+// two tables of 16 products, one per nibble, which fit in a vector register,
+// so that a byte shuffle looks up 16 or 32 products at once.
+// The bytes left after the last full vector go through Mul
+
+use crate::Nim8;
+
+// the products of the scalar by the low and the high nibbles
+#[derive(Clone)]
+#[cfg_attr(
+    not(any(target_arch = "x86_64", target_arch = "aarch64")),
+    allow(dead_code)
+)]
+pub(crate) struct Nibbles {
+    low: [u8; 16],
+    high: [u8; 16],
+}
+
+impl Nibbles {
+    pub(crate) fn new(scalar: Nim8) -> Self {
+        let mut nibbles = Nibbles {
+            low: [0; 16],
+            high: [0; 16],
+        };
+
+        for i in 0..16 {
+            nibbles.low[i] = (scalar * Nim8::new(i as u8)).get();
+            nibbles.high[i] = (scalar * Nim8::new((i as u8) << 4)).get();
+        }
+
+        nibbles
+    }
+
+    // the same from the products of the scalar by every byte, without any product
+    pub(crate) fn from_table(table: &[u8; 256]) -> Self {
+        let mut nibbles = Nibbles {
+            low: [0; 16],
+            high: [0; 16],
+        };
+
+        for i in 0..16 {
+            nibbles.low[i] = table[i];
+            nibbles.high[i] = table[i << 4];
+        }
+
+        nibbles
+    }
+}
+
+/// Multiplies every byte of `src` as a `Nim8` by `scalar` and stores the products in `dst`.
+///
+/// The bytes are processed by vector byte shuffles if the CPU supports them
+/// (AVX2 or SSSE3 on x86-64, NEON on aarch64), which is detected at run time.
+///
+/// The complexity is *O*(*n*) for slices of *n* bytes.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+pub fn mul_slice(scalar: Nim8, src: &[u8], dst: &mut [u8]) {
+    mul_kernel::<false>(scalar, src, dst);
+}
+
+/// Multiplies every byte of `src` as a `Nim8` by `scalar` and adds the products to `dst`,
+/// the `a x + y` step of the erasure codes.
+///
+/// The bytes are processed as in [`mul_slice`].
+///
+/// The complexity is *O*(*n*) for slices of *n* bytes.
+///
+/// # Panics
+///
+/// Panics if the slices have different lengths.
+pub fn mul_add_slice(scalar: Nim8, src: &[u8], dst: &mut [u8]) {
+    mul_kernel::<true>(scalar, src, dst);
+}
+
+// the vector kernel of the CPU, then the portable products for the rest
+fn mul_kernel<const ADD: bool>(scalar: Nim8, src: &[u8], dst: &mut [u8]) {
+    assert_eq!(src.len(), dst.len(), "the slices have different lengths");
+
+    let done = if detected() {
+        mul_simd::<ADD>(&Nibbles::new(scalar), src, dst)
+    } else {
+        0
+    };
+
+    mul_portable::<ADD>(scalar, &src[done..], &mut dst[done..]);
+}
+
+// the products one byte at a time
+fn mul_portable<const ADD: bool>(scalar: Nim8, src: &[u8], dst: &mut [u8]) {
+    for (s, d) in src.iter().zip(dst) {
+        let product = (scalar * Nim8::new(*s)).get();
+
+        *d = if ADD { *d ^ product } else { product };
+    }
+}
+
+// the vector kernels by element type, for the slices of a Multiplier;
+// only bytes have one, the wider products have no shuffle form
+pub(crate) trait SliceKernel: Sized {
+    // the nibble tables of the scalar whose products by the low byte are in table
+    fn nibbles(_table: &[Self; 256]) -> Option<Nibbles> {
+        None
+    }
+
+    // the number of leading elements processed
+    fn mul_simd<const ADD: bool>(_nibbles: &Nibbles, _src: &[Self], _dst: &mut [Self]) -> usize {
+        0
+    }
+}
+
+impl SliceKernel for u8 {
+    #[inline]
+    fn nibbles(table: &[u8; 256]) -> Option<Nibbles> {
+        Some(Nibbles::from_table(table))
+    }
+
+    #[inline]
+    fn mul_simd<const ADD: bool>(nibbles: &Nibbles, src: &[u8], dst: &mut [u8]) -> usize {
+        mul_simd::<ADD>(nibbles, src, dst)
+    }
+}
+
+impl SliceKernel for u16 {}
+impl SliceKernel for u32 {}
+impl SliceKernel for u64 {}
+impl SliceKernel for u128 {}
+impl SliceKernel for usize {}
+
+// whether the CPU has a vector kernel; the detection is cached by std
+#[cfg(target_arch = "x86_64")]
+#[inline]
+fn detected() -> bool {
+    is_x86_feature_detected!("avx2") || is_x86_feature_detected!("ssse3")
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline]
+fn detected() -> bool {
+    std::arch::is_aarch64_feature_detected!("neon")
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+#[inline]
+fn detected() -> bool {
+    false
+}
+
+// the number of leading bytes processed by the vector kernel of the CPU
+#[cfg(target_arch = "x86_64")]
+fn mul_simd<const ADD: bool>(nibbles: &Nibbles, src: &[u8], dst: &mut [u8]) -> usize {
+    if is_x86_feature_detected!("avx2") {
+        // SAFETY: the CPU has AVX2
+        unsafe { x86::mul_avx2::<ADD>(nibbles, src, dst) }
+    } else if is_x86_feature_detected!("ssse3") {
+        // SAFETY: the CPU has SSSE3
+        unsafe { x86::mul_ssse3::<ADD>(nibbles, src, dst) }
+    } else {
+        0
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+fn mul_simd<const ADD: bool>(nibbles: &Nibbles, src: &[u8], dst: &mut [u8]) -> usize {
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        // SAFETY: the CPU has NEON
+        unsafe { arm::mul_neon::<ADD>(nibbles, src, dst) }
+    } else {
+        0
+    }
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+fn mul_simd<const ADD: bool>(_nibbles: &Nibbles, _src: &[u8], _dst: &mut [u8]) -> usize {
+    0
+}
+
+#[cfg(target_arch = "x86_64")]
+pub(crate) mod x86 {
+    use super::Nibbles;
+    use std::arch::x86_64::*;
+
+    // the kernels take slices of the same length
+    // and return the number of leading bytes they processed
+
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn mul_avx2<const ADD: bool>(
+        nibbles: &Nibbles,
+        src: &[u8],
+        dst: &mut [u8],
+    ) -> usize {
+        let low = _mm256_broadcastsi128_si256(_mm_loadu_si128(nibbles.low.as_ptr() as *const _));
+        let high = _mm256_broadcastsi128_si256(_mm_loadu_si128(nibbles.high.as_ptr() as *const _));
+        let mask = _mm256_set1_epi8(0x0f);
+
+        for (s, d) in src.chunks_exact(32).zip(dst.chunks_exact_mut(32)) {
+            let x = _mm256_loadu_si256(s.as_ptr() as *const _);
+
+            let mut y = _mm256_xor_si256(
+                _mm256_shuffle_epi8(low, _mm256_and_si256(x, mask)),
+                _mm256_shuffle_epi8(high, _mm256_and_si256(_mm256_srli_epi64(x, 4), mask)),
+            );
+            if ADD {
+                y = _mm256_xor_si256(y, _mm256_loadu_si256(d.as_ptr() as *const _));
+            }
+
+            _mm256_storeu_si256(d.as_mut_ptr() as *mut _, y);
+        }
+
+        src.len() / 32 * 32
+    }
+
+    #[target_feature(enable = "ssse3")]
+    pub(crate) unsafe fn mul_ssse3<const ADD: bool>(
+        nibbles: &Nibbles,
+        src: &[u8],
+        dst: &mut [u8],
+    ) -> usize {
+        let low = _mm_loadu_si128(nibbles.low.as_ptr() as *const _);
+        let high = _mm_loadu_si128(nibbles.high.as_ptr() as *const _);
+        let mask = _mm_set1_epi8(0x0f);
+
+        for (s, d) in src.chunks_exact(16).zip(dst.chunks_exact_mut(16)) {
+            let x = _mm_loadu_si128(s.as_ptr() as *const _);
+
+            let mut y = _mm_xor_si128(
+                _mm_shuffle_epi8(low, _mm_and_si128(x, mask)),
+                _mm_shuffle_epi8(high, _mm_and_si128(_mm_srli_epi64(x, 4), mask)),
+            );
+            if ADD {
+                y = _mm_xor_si128(y, _mm_loadu_si128(d.as_ptr() as *const _));
+            }
+
+            _mm_storeu_si128(d.as_mut_ptr() as *mut _, y);
+        }
+
+        src.len() / 16 * 16
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+pub(crate) mod arm {
+    use super::Nibbles;
+    use std::arch::aarch64::*;
+
+    // the kernel takes slices of the same length
+    // and returns the number of leading bytes it processed
+    #[target_feature(enable = "neon")]
+    pub(crate) unsafe fn mul_neon<const ADD: bool>(
+        nibbles: &Nibbles,
+        src: &[u8],
+        dst: &mut [u8],
+    ) -> usize {
+        let low = vld1q_u8(nibbles.low.as_ptr());
+        let high = vld1q_u8(nibbles.high.as_ptr());
+        let mask = vdupq_n_u8(0x0f);
+
+        for (s, d) in src.chunks_exact(16).zip(dst.chunks_exact_mut(16)) {
+            let x = vld1q_u8(s.as_ptr());
+
+            let mut y = veorq_u8(
+                vqtbl1q_u8(low, vandq_u8(x, mask)),
+                vqtbl1q_u8(high, vshrq_n_u8::<4>(x)),
+            );
+            if ADD {
+                y = veorq_u8(y, vld1q_u8(d.as_ptr()));
+            }
+
+            vst1q_u8(d.as_mut_ptr(), y);
+        }
+
+        src.len() / 16 * 16
+    }
+}
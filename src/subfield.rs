@@ -0,0 +1,203 @@
+// Tower decomposition, relative trace and norm to subfields
+// The subfield at level k + 1 is the quadratic extension of the subfield at level k
+// by X = 2^(2^k), whose conjugate is X + 1, so for a = ah X + al
+// the trace a + conj(a) is ah and the norm a conj(a) is ah^2 (X^2 + X) + al (al + ah);
+// the relative trace and norm to a lower level are compositions of these steps
+
+use super::Nimber;
+use crate::multiplication::{
+    combine, high_part, level, low_part, nimber_mul_fermat, nimber_mul_nimber, nimber_square,
+    Level, Shift,
+};
+use crate::repr::{field_level, NimberRepr};
+
+// the trace from level lvl to level to is the high part after 2^lvl - 2^to bits
+#[inline]
+pub(crate) fn nimber_trace_to<T: NimberRepr>(a: &Nimber<T>, lvl: Level, to: Level) -> Nimber<T> {
+    Nimber::from(a.x.shr_ref(((1 as Shift) << lvl) - ((1 as Shift) << to)))
+}
+
+pub(crate) fn nimber_norm_to<T: NimberRepr>(a: &Nimber<T>, lvl: Level, to: Level) -> Nimber<T> {
+    let mut ans = a.clone();
+
+    for lvl in (to..lvl).rev() {
+        let ah = high_part::<T>(&ans, lvl);
+        let al = low_part::<T>(&ans, lvl);
+        let mut asum = ah.clone();
+        asum += &al;
+
+        ans = nimber_mul_fermat::<T>(&nimber_square::<T>(&ah, lvl), lvl);
+        ans += nimber_mul_nimber::<T>(&al, &asum, lvl);
+    }
+
+    ans
+}
+
+impl<T: NimberRepr> Nimber<T> {
+    // level of the field a nimber is mapped from, checked against the target level
+    fn source_level(&self, level: Level) -> Level {
+        if let Some(max) = T::MAX_LEVEL {
+            assert!(level <= max, "the level exceeds the field of the type");
+        }
+
+        field_level::<T>(self).max(level)
+    }
+
+    /// Returns the relative trace of a nimber to the subfield at `level`,
+    /// that is, to the nimbers below `2^(2^level)`:
+    /// the sum of its conjugates `x^(q^i)` over that subfield, where `q = 2^(2^level)`.
+    ///
+    /// The trace is taken from the whole field of the type.
+    /// Unbounded nimbers are considered elements of the smallest subfield containing them,
+    /// or of the subfield at `level` itself if it is larger.
+    /// The trace to level 0 is the absolute [`trace`](Nimber::trace).
+    ///
+    /// The complexity is *O*(1).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `level` exceeds the level of the whole field of the type.
+    #[inline]
+    pub fn trace_to(&self, level: Level) -> Nimber<T> {
+        nimber_trace_to::<T>(self, self.source_level(level), level)
+    }
+
+    /// Returns the relative norm of a nimber to the subfield at `level`,
+    /// the product of its conjugates over that subfield.
+    ///
+    /// The field is the same as in [`trace_to`](Nimber::trace_to).
+    ///
+    /// The complexity is *O*(*n*^(log_2 3)).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `level` exceeds the level of the whole field of the type.
+    #[inline]
+    pub fn norm_to(&self, level: Level) -> Nimber<T> {
+        nimber_norm_to::<T>(self, self.source_level(level), level)
+    }
+
+    /// Splits a nimber into the halves `(high, low)` at `level`,
+    /// so that `self = high * X + low` for `X = 2^(2^level)`
+    /// and `low` lies in the subfield at `level`, the nimbers below `X`.
+    ///
+    /// If `self` lies in the subfield at `level + 1`, so does `high`,
+    /// and the halves are the coordinates of `self` over the subfield at `level`
+    /// in the basis `X, 1`. Since `X^2 = X + c` for `c = 2^(2^level - 1)`,
+    /// the product of such nimbers is
+    ///
+    /// `(ah X + al) (bh X + bl) = ((ah + al) (bh + bl) + al bl) X + (ah bh c + al bl)`,
+    ///
+    /// which is how nimbers are multiplied.
+    /// Splitting the halves again gives the coordinates over smaller subfields.
+    ///
+    /// The complexity is *O*(*n*).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `2^level` is not less than the number of bits of the type.
+    #[inline]
+    pub fn split_at_level(&self, level: Level) -> (Nimber<T>, Nimber<T>) {
+        if let Some(max) = T::MAX_LEVEL {
+            assert!(level < max, "the level must be below the field of the type");
+        }
+
+        (high_part::<T>(self, level), low_part::<T>(self, level))
+    }
+
+    /// Joins the halves `high` and `low` of a nimber at `level` into `high * X + low`
+    /// for `X = 2^(2^level)`, the inverse of [`split_at_level`](Nimber::split_at_level).
+    ///
+    /// The complexity is *O*(*n*).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `2^level` is not less than the number of bits of the type,
+    /// or if `high` or `low` does not lie in the subfield at `level`.
+    #[inline]
+    pub fn join_at_level(high: &Nimber<T>, low: &Nimber<T>, level: Level) -> Nimber<T> {
+        if let Some(max) = T::MAX_LEVEL {
+            assert!(level < max, "the level must be below the field of the type");
+        }
+        assert!(
+            self::level::<T>(high) <= level,
+            "the high half must lie in the subfield"
+        );
+        assert!(
+            self::level::<T>(low) <= level,
+            "the low half must lie in the subfield"
+        );
+
+        combine::<T>(high, low, level)
+    }
+}
+
+macro_rules! nimber_subfield_map {
+    ($t:ty => $($n:ty, $lvl:expr, $trace:ident, $norm:ident);*) => {
+        impl Nimber<$t> {
+            $(
+                #[doc = concat!("Returns the [`trace_to`](Nimber::trace_to) level ", stringify!($lvl), " as a `Nimber<", stringify!($n), ">`.")]
+                #[inline]
+                pub fn $trace(&self) -> Nimber<$n> {
+                    Nimber::from(self.trace_to($lvl).x as $n)
+                }
+
+                #[doc = concat!("Returns the [`norm_to`](Nimber::norm_to) level ", stringify!($lvl), " as a `Nimber<", stringify!($n), ">`.")]
+                #[inline]
+                pub fn $norm(&self) -> Nimber<$n> {
+                    Nimber::from(self.norm_to($lvl).x as $n)
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! nimber_split {
+    ($($t:ty => $h:ty);*) => {
+        $(
+            impl Nimber<$t> {
+                #[doc = concat!("Splits a nimber into the halves `(high, low)` of type `Nimber<", stringify!($h), ">`,")]
+                /// see [`split_at_level`](Nimber::split_at_level).
+                #[inline]
+                pub fn split(&self) -> (Nimber<$h>, Nimber<$h>) {
+                    (
+                        Nimber::from((self.x >> <$h>::BITS) as $h),
+                        Nimber::from(self.x as $h),
+                    )
+                }
+
+                /// Joins the halves `high` and `low` of a nimber, the inverse of [`split`](Nimber::split).
+                #[inline]
+                pub fn join(high: Nimber<$h>, low: Nimber<$h>) -> Self {
+                    Nimber::from(((high.x as $t) << <$h>::BITS) | low.x as $t)
+                }
+            }
+        )*
+    };
+}
+
+nimber_split!(
+    u16 => u8;
+    u32 => u16;
+    u64 => u32;
+    u128 => u64
+);
+
+nimber_subfield_map!(u16 =>
+    u8, 3, trace_to_nim8, norm_to_nim8
+);
+nimber_subfield_map!(u32 =>
+    u8, 3, trace_to_nim8, norm_to_nim8;
+    u16, 4, trace_to_nim16, norm_to_nim16
+);
+nimber_subfield_map!(u64 =>
+    u8, 3, trace_to_nim8, norm_to_nim8;
+    u16, 4, trace_to_nim16, norm_to_nim16;
+    u32, 5, trace_to_nim32, norm_to_nim32
+);
+nimber_subfield_map!(u128 =>
+    u8, 3, trace_to_nim8, norm_to_nim8;
+    u16, 4, trace_to_nim16, norm_to_nim16;
+    u32, 5, trace_to_nim32, norm_to_nim32;
+    u64, 6, trace_to_nim64, norm_to_nim64
+);
@@ -0,0 +1,170 @@
+// Table-driven arithmetic of the nimbers below 2^16
+// Every nonzero nimber of the subfield at level k is a power of a primitive element g,
+// so a b = g^(log a + log b) with the exponents taken modulo 2^(2^k) - 1.
+// The tables are built at compile time: the Nim8 ones by the plain recursion,
+// the Nim16 ones from the Nim8 ones by a single step of it.
+// The Nim16 ones hold 2 * (65535 + 65536) bytes, about 256 KiB of static data.
+// They serve as the base case of the recursion for all the wider types
+
+use super::Nimber;
+use crate::multiplication::Level;
+use crate::repr::NimberRepr;
+
+// the highest level handled by the tables
+pub(crate) const TABLE_LEVEL: Level = 4;
+
+struct Tables<const N: usize, const M: usize> {
+    exp: [u16; N],
+    log: [u16; M],
+}
+
+type Tables8 = Tables<255, 256>;
+type Tables16 = Tables<65535, 65536>;
+
+// product at level lvl <= 3 by the plain recursion
+const fn const_mul(a: u16, b: u16, lvl: u32) -> u16 {
+    if lvl == 0 {
+        return a & b;
+    }
+
+    let half = 1 << (lvl - 1);
+    let mask = (1 << half) - 1;
+    let (ah, al, bh, bl) = (a >> half, a & mask, b >> half, b & mask);
+
+    let low = const_mul(al, bl, lvl - 1);
+    let high = const_mul(ah ^ al, bh ^ bl, lvl - 1) ^ low;
+    let low = const_mul(const_mul(ah, bh, lvl - 1), 1 << (half - 1), lvl - 1) ^ low;
+
+    (high << half) | low
+}
+
+// product at level 4 by one step of the recursion over the Nim8 tables
+const fn const_mul_split(t8: &Tables8, a: u16, b: u16) -> u16 {
+    let (ah, al, bh, bl) = (a >> 8, a & 0xff, b >> 8, b & 0xff);
+
+    let low = t8.mul(al, bl);
+    let high = t8.mul(ah ^ al, bh ^ bl) ^ low;
+    let low = t8.mul(t8.mul(ah, bh), 0x80) ^ low;
+
+    (high << 8) | low
+}
+
+impl<const N: usize, const M: usize> Tables<N, M> {
+    // powers of the primitive element g, multiplied with the Nim8 tables if given
+    const fn new(g: u16, t8: Option<&Tables8>) -> Self {
+        let mut tables = Tables {
+            exp: [0; N],
+            log: [0; M],
+        };
+
+        let mut x = 1;
+        let mut i = 0;
+
+        while i < N {
+            tables.exp[i] = x;
+            tables.log[x as usize] = i as u16;
+
+            x = match t8 {
+                Some(t8) => const_mul_split(t8, x, g),
+                None => const_mul(x, g, 3),
+            };
+            i += 1;
+        }
+
+        tables
+    }
+
+    #[inline]
+    const fn exp(&self, e: usize) -> u16 {
+        self.exp[if e >= N { e - N } else { e }]
+    }
+
+    #[inline]
+    const fn mul(&self, a: u16, b: u16) -> u16 {
+        if a == 0 || b == 0 {
+            return 0;
+        }
+
+        self.exp(self.log[a as usize] as usize + self.log[b as usize] as usize)
+    }
+
+    #[inline]
+    const fn square(&self, a: u16) -> u16 {
+        if a == 0 {
+            return 0;
+        }
+
+        self.exp(2 * self.log[a as usize] as usize)
+    }
+
+    // the group order N is odd, so the square root of g^l is g^(l / 2) or g^((l + N) / 2)
+    #[inline]
+    const fn sqrt(&self, a: u16) -> u16 {
+        if a == 0 {
+            return 0;
+        }
+
+        let l = self.log[a as usize] as usize;
+
+        self.exp[if l % 2 == 0 { l / 2 } else { (l + N) / 2 }]
+    }
+
+    // the inverse of zero is zero, as in the recursion
+    #[inline]
+    const fn inverse(&self, a: u16) -> u16 {
+        if a == 0 {
+            return 0;
+        }
+
+        self.exp(N - self.log[a as usize] as usize)
+    }
+
+    #[inline]
+    const fn pow(&self, a: u16, exp: u128) -> u16 {
+        if a == 0 {
+            return if exp == 0 { 1 } else { 0 };
+        }
+
+        let e = (exp % N as u128) as usize;
+
+        self.exp[self.log[a as usize] as usize * e % N]
+    }
+}
+
+static TABLES8: Tables8 = Tables::new(18, None);
+static TABLES16: Tables16 = Tables::new(258, Some(&TABLES8));
+
+macro_rules! nimber_table_op {
+    ($raw:ident, $name:ident, $method:ident($($arg:ident: $t:ty),*)) => {
+        #[inline]
+        pub(crate) const fn $raw(a: u16, $($arg: $t,)* lvl: Level) -> u16 {
+            if lvl <= 3 {
+                TABLES8.$method(a, $($arg),*)
+            } else {
+                TABLES16.$method(a, $($arg),*)
+            }
+        }
+
+        #[inline]
+        pub(crate) fn $name<T: NimberRepr>(a: &Nimber<T>, $($arg: $t,)* lvl: Level) -> Nimber<T> {
+            Nimber::from(T::from_u16($raw(a.x.to_u16(), $($arg,)* lvl)))
+        }
+    };
+}
+
+nimber_table_op!(table_mul_u16, table_mul_small, mul(b: u16));
+nimber_table_op!(table_square_u16, table_square, square());
+nimber_table_op!(table_sqrt_u16, table_sqrt, sqrt());
+nimber_table_op!(table_inverse_u16, table_inverse, inverse());
+nimber_table_op!(table_pow_u16, table_pow, pow(exp: u128));
+
+#[inline]
+pub(crate) fn table_mul<T: NimberRepr>(a: &Nimber<T>, b: &Nimber<T>, lvl: Level) -> Nimber<T> {
+    table_mul_small::<T>(a, b.x.to_u16(), lvl)
+}
+
+// multiply by 1 << ((1 << lvl) - 1)
+#[inline]
+pub(crate) fn table_mul_fermat<T: NimberRepr>(a: &Nimber<T>, lvl: Level) -> Nimber<T> {
+    table_mul_small::<T>(a, 1 << ((1 << lvl) - 1), lvl)
+}
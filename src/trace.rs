@@ -0,0 +1,97 @@
+// Absolute trace and the Artin-Schreier equation x^2 + x = a
+// The subfield at level k + 1 is the extension of the subfield at level k
+// by X = 2^(2^k), which satisfies X^2 + X = 2^(2^k - 1),
+// so both problems reduce to the halves of a nimber
+
+use super::Nimber;
+use crate::multiplication::{
+    combine, high_part, low_part, nimber_mul_fermat, nimber_square, Level, Shift, Smallest,
+};
+use crate::repr::{field_level, NimberRepr};
+
+// the trace of a at level lvl is its bit number 2^lvl - 1
+#[inline]
+pub(crate) fn nimber_trace<T: NimberRepr>(a: &Nimber<T>, lvl: Level) -> Nimber<T> {
+    Nimber::from(a.x.shr_ref(((1 as Shift) << lvl) - 1)) & &Nimber::from(T::from(1 as Smallest))
+}
+
+// a linear map H such that H(a)^2 + H(a) = a whenever the trace of a at level lvl is 0
+pub(crate) fn nimber_half_trace<T: NimberRepr>(a: &Nimber<T>, lvl: Level) -> Nimber<T> {
+    let one = Nimber::from(T::from(1 as Smallest));
+
+    if lvl == 0 {
+        return Nimber::from(T::from(0 as Smallest));
+    }
+
+    let lvl = lvl - 1;
+
+    let ah = high_part::<T>(a, lvl);
+    let al = low_part::<T>(a, lvl);
+
+    // (xh X + xl)^2 + (xh X + xl) = (xh^2 + xh) X + xl^2 + xl + xh^2 (X^2 + X)
+    let mut xh = nimber_half_trace::<T>(&ah, lvl);
+    let mut rhs = al;
+    rhs += nimber_mul_fermat::<T>(&nimber_square::<T>(&xh, lvl), lvl);
+
+    // replacing xh with xh + 1 adds X^2 + X to rhs, which has trace 1
+    if nimber_trace::<T>(&rhs, lvl) == one {
+        xh += &one;
+        rhs += nimber_mul_fermat::<T>(&one, lvl);
+    }
+
+    let xl = nimber_half_trace::<T>(&rhs, lvl);
+
+    combine::<T>(&xh, &xl, lvl)
+}
+
+// both roots of x^2 + x = a at level lvl, if any
+pub(crate) fn nimber_artin_schreier<T: NimberRepr>(
+    a: &Nimber<T>,
+    lvl: Level,
+) -> Option<(Nimber<T>, Nimber<T>)> {
+    if nimber_trace::<T>(a, lvl) != Nimber::from(T::from(0 as Smallest)) {
+        return None;
+    }
+
+    let x = nimber_half_trace::<T>(a, lvl);
+    let mut y = x.clone();
+    y += Nimber::from(T::from(1 as Smallest));
+
+    Some((x, y))
+}
+
+impl<T: NimberRepr> Nimber<T> {
+    /// Returns the absolute trace of a nimber, `x + x^2 + x^4 + ... + x^(2^(n - 1))`,
+    /// which is either `0` or `1`.
+    ///
+    /// The trace is taken over the whole field of the type, that is, `n` is the number of bits.
+    /// Unbounded nimbers are considered elements of the smallest subfield containing them.
+    ///
+    /// The complexity is *O*(1).
+    #[inline]
+    pub fn trace(&self) -> Nimber<T> {
+        nimber_trace::<T>(self, field_level::<T>(self))
+    }
+
+    /// Returns the half-trace of a nimber,
+    /// a linear map `h` such that `h(a)^2 + h(a) = a` whenever the trace of `a` is zero.
+    ///
+    /// The field is the same as in [`trace`](Nimber::trace).
+    ///
+    /// The complexity is *O*(*n*^(log_2 3)).
+    #[inline]
+    pub fn half_trace(&self) -> Nimber<T> {
+        nimber_half_trace::<T>(self, field_level::<T>(self))
+    }
+
+    /// Solves the Artin–Schreier equation `x^2 + x = self`.
+    ///
+    /// The roots exist if and only if the [`trace`](Nimber::trace) is zero,
+    /// in which case they are `h` and `h + 1` for the [`half_trace`](Nimber::half_trace) `h`.
+    ///
+    /// The complexity is *O*(*n*^(log_2 3)).
+    #[inline]
+    pub fn solve_artin_schreier(&self) -> Option<(Nimber<T>, Nimber<T>)> {
+        nimber_artin_schreier::<T>(self, field_level::<T>(self))
+    }
+}